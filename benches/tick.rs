@@ -0,0 +1,68 @@
+// Temps d'un tick de `run_spiking` selon la taille du vocabulaire.
+//
+//     cargo bench --bench tick
+//
+// Les mesures sortent sur stderr. Aucun logger n'est installé : les messages
+// du cerveau (façade `log`) ne coûtent rien et n'apparaissent pas.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use ai_baby::{Brain, SimulationMode};
+use common::word;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::time::Instant;
+
+const SIZES: &[usize] = &[1_000, 10_000, 100_000];
+const FAN_OUT: usize = 8; // Synapses sortantes par neurone
+const ACTIVE_RATIO: f64 = 0.01; // Part des neurones actifs au départ
+const TICKS: usize = 10;
+
+// Réseau des tests, avec seulement une petite part de neurones actifs
+fn build(size: usize, simulation: SimulationMode) -> Brain {
+    let mut brain = common::network(size as u64, simulation, size, FAN_OUT);
+    let mut rng = ChaCha8Rng::seed_from_u64(size as u64);
+    for n in brain.neurons.iter_mut() {
+        n.v = if rng.gen_bool(ACTIVE_RATIO) { 1.0 } else { 0.0 };
+    }
+    brain
+}
+
+fn main() {
+    eprintln!(
+        "{:>14} {:>10} {:>12} {:>14} {:>14}",
+        "mode", "neurones", "synapses", "ms/tick", "spikes/tick"
+    );
+
+    for &size in SIZES {
+        for simulation in [
+            SimulationMode::Dense,
+            SimulationMode::EventDriven,
+            SimulationMode::Parallel,
+        ] {
+            bench(size, simulation);
+        }
+    }
+}
+
+fn bench(size: usize, simulation: SimulationMode) {
+    let mut brain = build(size, simulation);
+    let synapses = brain.synapses.len();
+    let seeds: Vec<String> = (0..10).map(|i| word(i * size / 10)).collect();
+
+    let start = Instant::now();
+    let history = brain.run_spiking(&seeds, TICKS);
+    let elapsed = start.elapsed();
+
+    let ticks = history.len().max(1);
+    let spikes: usize = history.iter().map(|fired| fired.len()).sum();
+    eprintln!(
+        "{:>14} {:>10} {:>12} {:>14.3} {:>14.1}",
+        format!("{:?}", simulation),
+        size,
+        synapses,
+        elapsed.as_secs_f64() * 1000.0 / ticks as f64,
+        spikes as f64 / ticks as f64
+    );
+}
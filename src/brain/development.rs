@@ -0,0 +1,184 @@
+// Développement du langage.
+//
+// Le bébé vieillit au fil des tours de conversation, des spikes et des mots
+// appris. Son âge fixe un stade qui bride la forme de ses réponses : il
+// babille d'abord des syllabes redoublées, puis dit un mot, puis deux, puis
+// de petites phrases. On ne régresse jamais : l'âge ne fait que croître.
+
+use crate::brain::Brain;
+use crate::logging;
+use crate::tokenizer::Word;
+use log::info;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Syllabes du babillage spontané, quand il n'a rien à imiter
+const BABBLE: &[&str] = &["ba", "ma", "pa", "da", "ta", "ga", "na"];
+const VOWELS: &str = "aeiouyàâäéèêëîïôöùûüœæ";
+
+/// Stade de développement du langage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Babbling,
+    SingleWords,
+    TwoWords,
+    ShortPhrases,
+}
+
+impl Stage {
+    /// Nombre maximal de mots d'une réponse à ce stade.
+    pub fn max_words(self) -> usize {
+        match self {
+            Stage::Babbling => 2,
+            Stage::SingleWords => 1,
+            Stage::TwoWords => 2,
+            Stage::ShortPhrases => usize::MAX,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::Babbling => "babillage",
+            Stage::SingleWords => "premiers mots",
+            Stage::TwoWords => "deux mots",
+            Stage::ShortPhrases => "petites phrases",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Âge du bébé (la taille du vocabulaire se lit dans [`Brain::vocab`]).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Age {
+    pub turns: u64,
+    pub spikes: u64,
+}
+
+/// Ce qu'il faut avoir vécu pour passer un stade : les trois à la fois.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Milestone {
+    pub turns: u64,
+    pub spikes: u64,
+    pub vocabulary: usize,
+}
+
+/// Réglages du développement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DevelopmentParams {
+    pub enabled: bool, // Sinon, il parle comme un grand dès le départ
+    pub single_words: Milestone,
+    pub two_words: Milestone,
+    pub short_phrases: Milestone,
+}
+
+impl Default for DevelopmentParams {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            single_words: Milestone {
+                turns: 5,
+                spikes: 10,
+                vocabulary: 15,
+            },
+            two_words: Milestone {
+                turns: 20,
+                spikes: 60,
+                vocabulary: 40,
+            },
+            short_phrases: Milestone {
+                turns: 50,
+                spikes: 200,
+                vocabulary: 100,
+            },
+        }
+    }
+}
+
+/// Début d'un mot jusqu'à sa première voyelle incluse : « maman » → « ma ».
+fn first_syllable(word: &str) -> String {
+    let mut syllable = String::new();
+    let mut seen_vowel = false;
+    for c in word.chars() {
+        let vowel = VOWELS.contains(c);
+        if seen_vowel && !vowel {
+            break;
+        }
+        seen_vowel |= vowel;
+        syllable.push(c);
+    }
+    syllable
+}
+
+impl Brain {
+    /// Stade atteint avec l'âge actuel.
+    pub fn stage(&self) -> Stage {
+        let params = &self.development;
+        if !params.enabled {
+            return Stage::ShortPhrases;
+        }
+        let reached = |m: &Milestone| {
+            self.age.turns >= m.turns
+                && self.age.spikes >= m.spikes
+                && self.vocab.len() >= m.vocabulary
+        };
+        if reached(&params.short_phrases) {
+            Stage::ShortPhrases
+        } else if reached(&params.two_words) {
+            Stage::TwoWords
+        } else if reached(&params.single_words) {
+            Stage::SingleWords
+        } else {
+            Stage::Babbling
+        }
+    }
+
+    /// Un tour de plus, pendant lequel `spikes` neurones ont déchargé.
+    pub(crate) fn grow_older(&mut self, spikes: u64) {
+        self.age.turns += 1;
+        self.age.spikes += spikes;
+    }
+
+    /// Réponse bridée par le stade : [`Brain::generate_reply`] limité au
+    /// nombre de mots du stade, ou babillage des premières syllabes.
+    pub fn reply(&mut self, seed_words: &[Word]) -> String {
+        self.speak(seed_words).1
+    }
+
+    /// Ce qu'il voulait dire (les mots générés, qui servent à
+    /// l'apprentissage et au feedback) et ce qu'il a dit (leur babillage, tant
+    /// qu'il babille).
+    pub(crate) fn speak(&mut self, seed_words: &[Word]) -> (String, String) {
+        let stage = self.stage();
+        let max_len = self.generation.max_len;
+        self.generation.max_len = max_len.min(stage.max_words());
+        let reply = self.generate_reply(seed_words);
+        self.generation.max_len = max_len;
+
+        if stage != Stage::Babbling {
+            return (reply.clone(), reply);
+        }
+
+        // Syllabes redoublées : ce qu'il voulait dire, ou n'importe quoi
+        let mut syllables: Vec<String> = reply
+            .split_whitespace()
+            .map(first_syllable)
+            .filter(|s| !s.is_empty())
+            .collect();
+        if syllables.is_empty() {
+            let i = self.rng.gen_range(0..BABBLE.len());
+            syllables.push(BABBLE[i].to_string());
+        }
+        let babble = syllables
+            .iter()
+            .map(|s| s.repeat(2))
+            .collect::<Vec<_>>()
+            .join(" ");
+        info!(target: logging::REPLY, "Babillage ({}): {}", stage, babble);
+        (reply, babble)
+    }
+}
@@ -0,0 +1,202 @@
+// Simulation événementielle : seuls les neurones qui ont quelque chose à
+// faire sont touchés à chaque tick.
+//
+// - « chauds » : entrée en attente, potentiel élevé ou période réfractaire.
+//   Ils suivent exactement la même dynamique que le mode dense.
+// - « refroidis » : le modèle du neurone le dit incapable de spiker sans
+//   entrée. On ne les touche plus ; leur évolution libre pendant les ticks
+//   manqués est appliquée paresseusement quand ils reçoivent une entrée (ou
+//   en fin de run).
+// - au repos : tous les autres, ignorés.
+//
+// Les deux ensembles sont gardés d'un run à l'autre : seul le premier run
+// parcourt tout le vocabulaire. Un run dense ou un rechargement les
+// abandonne, et le run événementiel suivant les reconstruit. La dérive des
+// seuils passe par `Brain::threshold_drift`, sans toucher les neurones.
+//
+// Écarts tolérés avec le mode dense : un neurone au repos ne suit plus la
+// petite oscillation globale (quelques centièmes de potentiel) et un neurone
+// refroidi ne peut pas spiker sans entrée.
+
+use crate::Word;
+use crate::brain::neurons::with_drift;
+use crate::brain::orchestrator::tick_factors;
+use crate::brain::{Brain, NeuronId};
+use crate::neuron::Neuron;
+use crate::neuron_model::NeuronModel;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Manière de faire avancer la simulation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimulationMode {
+    /// Tous les neurones à chaque tick.
+    #[default]
+    Dense,
+    /// Seulement les neurones actifs : passe à l'échelle de grands
+    /// vocabulaires.
+    EventDriven,
+    /// Comme `Dense`, réparti sur tous les cœurs (feature `parallel`) ;
+    /// résultats identiques au bit près. Sans la feature, équivaut à `Dense`.
+    Parallel,
+}
+
+// En dessous, un neurone est considéré au repos (potentiel ramené à 0).
+const REST_LEVEL: f32 = 0.1;
+
+// Potentiel et adaptation retombés : plus rien à rattraper
+fn at_rest(n: &Neuron) -> bool {
+    n.v.abs() <= REST_LEVEL && n.u.abs() <= REST_LEVEL
+}
+
+/// File des neurones éveillés, gardée d'un run à l'autre.
+pub(crate) struct EventQueue {
+    hot: BTreeSet<NeuronId>,
+    /// Neurone refroidi → premier tick dont la décroissance reste à appliquer.
+    cooling: BTreeMap<NeuronId, usize>,
+    /// Facteurs (oscillation, circadien) et dérive des seuils des ticks déjà
+    /// joués dans le run.
+    factors: Vec<(f32, f32, f32)>,
+}
+
+impl EventQueue {
+    // Un seul parcours complet, au premier run, pour trouver qui est éveillé.
+    pub(crate) fn new(brain: &Brain) -> Self {
+        let mut hot = BTreeSet::new();
+        let mut cooling = BTreeMap::new();
+        for (id, _) in brain.vocab.iter() {
+            let n = &brain.neurons[id.index()];
+            let model = n.model.unwrap_or(brain.neuron_model);
+            if n.refractory > 0 || !model.settled(n) {
+                hot.insert(id);
+            } else if !at_rest(n) {
+                cooling.insert(id, 0);
+            }
+        }
+        Self {
+            hot,
+            cooling,
+            factors: Vec::new(),
+        }
+    }
+}
+
+impl Brain {
+    // Applique aux neurones refroidis les ticks manqués jusqu'à `until`
+    // (exclu) : dynamique sans entrée, oscillation et modulation circadienne.
+    fn catch_up(&mut self, queue: &EventQueue, id: NeuronId, since: usize, until: usize) {
+        let n = &mut self.neurons[id.index()];
+        let model = n.model.unwrap_or(self.neuron_model);
+        for &(oscillation_factor, circadian_factor, drift) in &queue.factors[since..until] {
+            with_drift(n, drift, |n| model.integrate(n, 0.0));
+            n.v += oscillation_factor * 0.05;
+            n.v *= circadian_factor;
+        }
+    }
+
+    // Des mots entendus en cours de run réveillent leurs neurones, avant de
+    // recevoir leur stimulation
+    pub(crate) fn wake(&mut self, queue: &mut EventQueue, words: &[Word], t: usize) {
+        let ids: Vec<NeuronId> = words.iter().filter_map(|w| self.vocab.id(w)).collect();
+        for id in ids {
+            if let Some(since) = queue.cooling.remove(&id) {
+                self.catch_up(queue, id, since, t);
+            }
+            queue.hot.insert(id);
+        }
+    }
+
+    pub(crate) fn tick_event_driven(
+        &mut self,
+        queue: &mut EventQueue,
+        t: usize,
+    ) -> BTreeSet<NeuronId> {
+        let (oscillation_factor, circadian_factor) = tick_factors(t);
+        let mut fired: BTreeSet<NeuronId> = BTreeSet::new();
+
+        // Les neurones refroidis restent sous 0.9 : seuls les chauds sont actifs
+        let active: Vec<NeuronId> = queue
+            .hot
+            .iter()
+            .copied()
+            .filter(|id| self.neurons[id.index()].v > 0.9)
+            .collect();
+        let inputs = self.synaptic_inputs(active);
+
+        // Une entrée réveille sa cible (en rattrapant sa décroissance)
+        for &id in inputs.keys() {
+            if let Some(since) = queue.cooling.remove(&id) {
+                self.catch_up(queue, id, since, t);
+            }
+            queue.hot.insert(id);
+        }
+
+        let drift = self.threshold_drift;
+        let mut cooled = vec![];
+        for &id in &queue.hot {
+            let n = &mut self.neurons[id.index()];
+            let model = n.model.unwrap_or(self.neuron_model);
+
+            if n.refractory == 0 {
+                let input_sum = inputs.get(&id).copied().unwrap_or(0.0);
+                // Le seuil du neurone tient compte de la dérive commune
+                with_drift(n, drift, |n| {
+                    model.integrate(n, input_sum * n.gain);
+                    if model.fires(n) {
+                        fired.insert(id);
+                        model.reset(n);
+                        n.refractory = model.refractory();
+                        n.fired_count += 1;
+                    }
+                });
+            }
+            if n.refractory > 0 {
+                n.refractory -= 1;
+            }
+
+            // Limiter les activations excessives
+            n.v = n.v.min(1.5);
+            n.v += oscillation_factor * 0.05;
+            n.v *= circadian_factor;
+
+            if n.refractory == 0 && model.settled(n) {
+                cooled.push(id);
+            }
+        }
+
+        for id in cooled {
+            queue.hot.remove(&id);
+            let n = &mut self.neurons[id.index()];
+            if !at_rest(n) {
+                queue.cooling.insert(id, t + 1);
+            } else {
+                n.v = 0.0;
+            }
+        }
+
+        queue
+            .factors
+            .push((oscillation_factor, circadian_factor, drift));
+        self.threshold_drift += oscillation_factor * 0.01;
+        fired
+    }
+
+    // Fin de run : les neurones refroidis sont mis à jour, ceux retombés au
+    // repos quittent la file, et la file est gardée pour le run suivant.
+    pub(crate) fn finish_event_driven(&mut self, mut queue: EventQueue) {
+        let end = queue.factors.len();
+        let cooling = std::mem::take(&mut queue.cooling);
+        for (id, since) in cooling {
+            self.catch_up(&queue, id, since, end);
+            let n = &mut self.neurons[id.index()];
+            if at_rest(n) {
+                n.v = 0.0;
+            } else {
+                queue.cooling.insert(id, 0);
+            }
+        }
+        queue.factors.clear();
+        self.events = Some(queue);
+    }
+}
@@ -0,0 +1,130 @@
+// Feedback explicite sur la réponse précédente.
+//
+// La réaction de l'utilisateur reçoit un score dans [-1, 1] : mots d'éloge
+// ou de reproche, commandes `/good` et `/bad`, emoji. Les synapses qui ont
+// produit la réponse sont renforcées ou affaiblies d'autant, plus ou moins
+// fort selon la chimie du moment, puis un pic (ou un creux) de dopamine
+// convertit les traces d'éligibilité.
+
+use crate::brain::{Brain, SynapseKey};
+use crate::logging;
+use crate::tokenizer::{Word, tokenize};
+use log::{info, trace};
+
+pub(crate) const POSITIVE_WORDS: &[&str] = &[
+    "bien", "super", "bravo", "oui", "génial", "merci", "parfait", "cool", "top",
+];
+const NEGATIVE_WORDS: &[&str] = &[
+    "non", "mal", "nul", "nulle", "faux", "bof", "arrête", "mauvais",
+];
+const POSITIVE_EMOJI: &[&str] = &[
+    "👍", "😊", "🙂", "😀", "😃", "😄", "😍", "🥰", "❤", "👏", "🎉",
+];
+const NEGATIVE_EMOJI: &[&str] = &["👎", "😠", "😡", "😢", "😞", "🙁", "☹", "💩"];
+
+/// Commande d'approbation sans appel.
+pub const GOOD_COMMAND: &str = "/good";
+/// Commande de désapprobation sans appel.
+pub const BAD_COMMAND: &str = "/bad";
+
+/// Score de la réaction de l'utilisateur, dans [-1, 1] : 0 si elle ne juge
+/// rien. `/good` et `/bad` l'emportent sur tout le reste ; sinon chaque mot
+/// ou emoji compte pour moitié, et « pas » inverse le mot qui le suit.
+pub fn feedback_score(input: &str) -> f32 {
+    let commands: Vec<&str> = input.split_whitespace().collect();
+    match (
+        commands.contains(&GOOD_COMMAND),
+        commands.contains(&BAD_COMMAND),
+    ) {
+        (true, false) => return 1.0,
+        (false, true) => return -1.0,
+        _ => {}
+    }
+
+    let mut score = 0.0;
+    let mut negated = false;
+    for w in tokenize(&without_commands(input)) {
+        let polarity = if POSITIVE_WORDS.contains(&w.as_str()) {
+            1.0
+        } else if NEGATIVE_WORDS.contains(&w.as_str()) {
+            -1.0
+        } else {
+            0.0
+        };
+        score += if negated { -polarity } else { polarity };
+        negated = w == "pas";
+    }
+    for emoji in POSITIVE_EMOJI {
+        score += input.matches(emoji).count() as f32;
+    }
+    for emoji in NEGATIVE_EMOJI {
+        score -= input.matches(emoji).count() as f32;
+    }
+
+    (score / 2.0).clamp(-1.0, 1.0)
+}
+
+/// Le message sans les commandes de feedback, qui ne sont pas des mots à
+/// apprendre.
+pub fn without_commands(input: &str) -> String {
+    input
+        .split_whitespace()
+        .filter(|w| *w != GOOD_COMMAND && *w != BAD_COMMAND)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Brain {
+    /// Feedback explicite de l'utilisateur sur la dernière réponse : les
+    /// synapses qui ont produit `last_reply_words` gagnent (ou perdent)
+    /// `score × feedback_lr`, modulé par la chimie du moment.
+    pub fn apply_feedback(&mut self, feedback: &str, last_reply_words: &[Word]) {
+        let score = feedback_score(feedback);
+        if score == 0.0 || last_reply_words.is_empty() {
+            return;
+        }
+
+        let gain = self.modulator.feedback_gain(score);
+        let delta = score * self.reward.feedback_lr * gain;
+        info!(
+            target: logging::LEARNING,
+            "Feedback {:+.2} sur {:?} (gain chimique {:.2}, Δ = {:+.3})",
+            score, last_reply_words, gain, delta
+        );
+
+        let producing: Vec<SynapseKey> = self
+            .last_reply_synapses
+            .iter()
+            .filter(|key| {
+                last_reply_words
+                    .iter()
+                    .any(|w| w == self.vocab.word(key.post))
+            })
+            .copied()
+            .collect();
+        for key in producing {
+            if let Some(syn) = self.synapses.get_mut(&key)
+                && syn.is_excitatory()
+            {
+                let before = syn.strength;
+                syn.strength = (before + delta).clamp(0.0, self.exc_max);
+                trace!(
+                    target: logging::LEARNING,
+                    "  {} → {} : {:.3} → {:.3}",
+                    self.vocab.word(key.pre),
+                    self.vocab.word(key.post),
+                    before,
+                    syn.strength
+                );
+            }
+        }
+
+        // Les mots d'approbation peuvent faire naître neurones et synapses
+        let (neurons, synapses) = (self.neurons.len(), self.synapses.len());
+        self.reward_reply(score, &tokenize(feedback), last_reply_words);
+        self.record_growth(
+            self.neurons.len().saturating_sub(neurons),
+            self.synapses.len().saturating_sub(synapses),
+        );
+    }
+}
@@ -0,0 +1,152 @@
+// Génération de réponse par activation qui se propage.
+//
+// Les mots entendus s'allument, leur activation se propage le long des
+// synapses excitatrices, et à chaque vague un mot est tiré parmi ceux qui
+// reçoivent assez d'excitation : la réponse suit l'ordre dans lequel les mots
+// « s'allument », pas l'ordre alphabétique. Chaque mot dit inhibe ses
+// concurrents par ses synapses GABA, et l'activation des vagues précédentes
+// s'éteint peu à peu.
+//
+// Le tirage passe par une température : la noradrénaline concentre le choix
+// sur le mot le plus excité, la dopamine pousse à explorer.
+
+use crate::brain::{Brain, NeuronId, SynapseKey};
+use crate::logging;
+use crate::tokenizer::{Tokenizer, Word};
+use log::{debug, info};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Réglages du générateur de réponse.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GenerationParams {
+    pub max_len: usize,   // Nombre maximal de mots
+    pub threshold: f32,   // Excitation minimale d'un candidat
+    pub decay: f32,       // Activation conservée d'une vague à l'autre
+    pub temperature: f32, // Température de base du tirage
+    pub exploration: f32, // Poids de la dopamine sur la température
+    pub focus: f32,       // Poids de la noradrénaline sur la température
+}
+
+impl Default for GenerationParams {
+    fn default() -> Self {
+        Self {
+            max_len: 5,
+            threshold: 0.1,
+            decay: 0.5,
+            temperature: 0.3,
+            exploration: 2.0,
+            focus: 4.0,
+        }
+    }
+}
+
+impl Brain {
+    /// Température courante du tirage des mots.
+    pub fn reply_temperature(&self) -> f32 {
+        let params = &self.generation;
+        params.temperature * (1.0 + params.exploration * self.modulator.dopamine)
+            / (1.0 + params.focus * self.modulator.noradrenaline)
+    }
+
+    /// Construit une réponse ordonnée à partir des mots-graines. Les
+    /// synapses empruntées sont retenues dans
+    /// [`Brain::last_reply_synapses`].
+    pub fn generate_reply(&mut self, seed_words: &[Word]) -> String {
+        debug!(
+            target: logging::REPLY,
+            "Génération de réponse à partir de seed_words: {:?}",
+            seed_words
+        );
+
+        let params = self.generation.clone();
+        let temperature = self.reply_temperature().max(1e-3);
+
+        let mut activation: BTreeMap<NeuronId, f32> = seed_words
+            .iter()
+            .filter_map(|w| self.vocab.id(w))
+            .map(|id| (id, 1.0))
+            .collect();
+        let mut said: Vec<NeuronId> = vec![];
+        let mut used: Vec<SynapseKey> = vec![];
+
+        while said.len() < params.max_len {
+            // Excitation reçue par chaque mot pas encore allumé
+            let mut drive: BTreeMap<NeuronId, f32> = BTreeMap::new();
+            for (&pre, &act) in &activation {
+                for (post, syn) in self.synapses.outgoing(pre) {
+                    if syn.is_excitatory() && !activation.contains_key(&post) {
+                        *drive.entry(post).or_default() += act * syn.strength;
+                    }
+                }
+            }
+            // Inhibition latérale par les mots déjà dits
+            for (&post, d) in drive.iter_mut() {
+                for &winner in &said {
+                    if let Some(syn) = self.synapses.get(&SynapseKey::new(winner, post))
+                        && syn.is_inhibitory()
+                    {
+                        *d -= syn.strength;
+                    }
+                }
+            }
+            let candidates: Vec<(NeuronId, f32)> = drive
+                .into_iter()
+                .filter(|&(_, d)| d > params.threshold)
+                .collect();
+            if candidates.is_empty() {
+                break;
+            }
+
+            let chosen = self.sample(&candidates, temperature);
+            used.extend(
+                activation
+                    .keys()
+                    .map(|&pre| SynapseKey::new(pre, chosen))
+                    .filter(|key| {
+                        self.synapses
+                            .get(key)
+                            .is_some_and(|syn| syn.is_excitatory())
+                    }),
+            );
+
+            // Le mot choisi s'allume, les autres s'éteignent peu à peu
+            for act in activation.values_mut() {
+                *act *= params.decay;
+            }
+            activation.insert(chosen, 1.0);
+            said.push(chosen);
+        }
+
+        self.last_reply_synapses = used;
+        let response = self.tokenizer.detokenize(&self.vocab.resolve(said));
+        info!(
+            target: logging::REPLY,
+            "Réponse générée (température {:.2}): {}",
+            temperature, response
+        );
+        response
+    }
+
+    // Tirage softmax des candidats, dans l'ordre des identifiants
+    fn sample(&mut self, candidates: &[(NeuronId, f32)], temperature: f32) -> NeuronId {
+        let best = candidates
+            .iter()
+            .map(|&(_, d)| d)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let weights: Vec<f32> = candidates
+            .iter()
+            .map(|&(_, d)| ((d - best) / temperature).exp())
+            .collect();
+        let mut r = self.rng.r#gen::<f32>() * weights.iter().sum::<f32>();
+        for (&(id, _), w) in candidates.iter().zip(&weights) {
+            if r < *w {
+                return id;
+            }
+            r -= w;
+        }
+        candidates[candidates.len() - 1].0
+    }
+}
@@ -0,0 +1,85 @@
+// Homéostasie : chaque neurone vise un taux de décharge cible.
+//
+// Un neurone trop bavard remonte son seuil, baisse son gain d'entrée et voit
+// ses synapses entrantes réduites ; un neurone muet fait l'inverse. Tout se
+// règle lentement, à l'échelle de `rate_tau` ticks, pour ne pas effacer ce
+// qui a été appris.
+//
+// Pendant un run, c'est l'adaptation du modèle de neurone qui freine un
+// neurone sollicité : chez le LIF, chaque spike relève son seuil un moment
+// (voir `Lif::adaptation`).
+
+use crate::brain::{Brain, NeuronId};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Réglages de l'homéostasie.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HomeostasisParams {
+    pub enabled: bool,
+    pub target_rate: f32, // Spikes par tick visés (sauf cible propre au neurone)
+    pub rate_tau: f32,    // Fenêtre de l'estimation du taux, en ticks
+    pub threshold_lr: f32, // Vitesse d'adaptation du seuil
+    pub min_threshold: f32, // Bornes du seuil
+    pub max_threshold: f32,
+    pub gain_lr: f32, // Vitesse d'adaptation du gain d'entrée
+    pub min_gain: f32,
+    pub max_gain: f32,
+    pub scaling_lr: f32, // Mise à l'échelle des synapses entrantes
+}
+
+impl Default for HomeostasisParams {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            target_rate: 0.05,
+            rate_tau: 20.0,
+            threshold_lr: 0.02,
+            min_threshold: 0.5,
+            max_threshold: 3.0,
+            gain_lr: 0.002,
+            min_gain: 0.1,
+            max_gain: 2.0,
+            scaling_lr: 0.01,
+        }
+    }
+}
+
+impl Brain {
+    /// Met à jour les taux de décharge estimés avec l'historique d'un run,
+    /// puis rapproche chaque neurone de sa cible.
+    pub(crate) fn apply_homeostasis(&mut self, spikes_history: &[BTreeSet<NeuronId>]) {
+        let params = self.homeostasis.clone();
+        if !params.enabled {
+            return;
+        }
+
+        // Écart relatif à la cible, borné : un neurone muet vaut -1
+        let mut errors = Vec::with_capacity(self.neurons.len());
+        for (i, n) in self.neurons.iter_mut().enumerate() {
+            let id = NeuronId(i as u32);
+            for fired in spikes_history {
+                let spiked = if fired.contains(&id) { 1.0 } else { 0.0 };
+                n.rate += (spiked - n.rate) / params.rate_tau;
+            }
+
+            let target = n.target_rate.unwrap_or(params.target_rate).max(1e-3);
+            n.threshold = (n.threshold + params.threshold_lr * (n.rate - target))
+                .clamp(params.min_threshold, params.max_threshold);
+
+            let error = ((n.rate - target) / target).clamp(-1.0, 1.0);
+            n.gain = (n.gain - params.gain_lr * error).clamp(params.min_gain, params.max_gain);
+            errors.push(error);
+        }
+
+        // Mise à l'échelle multiplicative des entrées excitatrices
+        for (key, syn) in self.synapses.iter_mut() {
+            if syn.is_excitatory() {
+                let error = errors[key.post.index()];
+                syn.strength =
+                    (syn.strength * (1.0 - params.scaling_lr * error)).clamp(0.0, self.exc_max);
+            }
+        }
+    }
+}
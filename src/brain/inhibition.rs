@@ -0,0 +1,101 @@
+// Circuits inhibiteurs (GABA).
+//
+// Deux mots entrent en compétition quand ils répondent au même mot sans être
+// associés entre eux, et s'allument ensemble : ils se relient alors par des
+// synapses inhibitrices. Le stress fait de même pour tout ce qui s'embrase
+// en même temps. Ces synapses apprennent avec leurs propres réglages
+// (`lr_inh`, `inh_max`) : elles se renforcent quand leur cible décharge
+// au-dessus de son taux cible et s'affaiblissent sinon, ce qui équilibre
+// l'excitation.
+
+use crate::brain::{Brain, NeuronId, SynapseKey, synapses};
+use crate::neurotransmitter::Neurotransmitter;
+use std::collections::BTreeSet;
+
+// Stress au-delà duquel les co-activations deviennent inhibitrices
+const STRESS_LEVEL: f32 = 0.2;
+
+impl Brain {
+    /// Crée et apprend les synapses inhibitrices à partir d'un historique de
+    /// spikes.
+    pub(crate) fn learn_inhibition(&mut self, spikes_history: &[BTreeSet<NeuronId>]) {
+        self.grow_inhibition(spikes_history);
+        self.apply_inhibitory_plasticity(spikes_history);
+    }
+
+    fn grow_inhibition(&mut self, spikes_history: &[BTreeSet<NeuronId>]) {
+        let mut grown: Vec<(SynapseKey, f32)> = vec![];
+
+        for fired in spikes_history {
+            for &a in fired {
+                for &b in fired {
+                    if a != b && self.compete(a, b) {
+                        grown.push((SynapseKey::new(a, b), self.lr_inh));
+                    }
+                }
+            }
+        }
+
+        let stress = self.modulator.stress;
+        if stress > STRESS_LEVEL {
+            let last_fired = spikes_history.last().cloned().unwrap_or_default();
+            for &a in &last_fired {
+                for &b in &last_fired {
+                    if a != b {
+                        grown.push((SynapseKey::new(a, b), self.lr_inh * stress));
+                    }
+                }
+            }
+        }
+
+        for (key, amount) in grown {
+            // Une paire déjà reliée par une synapse excitatrice le reste
+            if self
+                .synapses
+                .get(&key)
+                .is_some_and(|syn| !syn.is_inhibitory())
+            {
+                continue;
+            }
+            let syn = synapses::ensure_synapse(
+                &mut self.synapses,
+                key.pre,
+                key.post,
+                Neurotransmitter::Gaba,
+            );
+            syn.strength = (syn.strength + amount).min(self.inh_max);
+        }
+    }
+
+    // `a` et `b` répondent à un même mot, sans synapse excitatrice entre eux
+    fn compete(&self, a: NeuronId, b: NeuronId) -> bool {
+        let excites = |pre, post| {
+            self.synapses
+                .get(&SynapseKey::new(pre, post))
+                .is_some_and(|syn| syn.is_excitatory())
+        };
+        if excites(a, b) || excites(b, a) {
+            return false;
+        }
+        self.synapses
+            .predecessors(a)
+            .any(|pre| pre != b && excites(pre, a) && excites(pre, b))
+    }
+
+    fn apply_inhibitory_plasticity(&mut self, spikes_history: &[BTreeSet<NeuronId>]) {
+        let fired: BTreeSet<NeuronId> = spikes_history.iter().flatten().copied().collect();
+        let modulation = 1.0 + self.modulator.modulate_neurotransmitter("GABA");
+        let target_rate = self.homeostasis.target_rate;
+
+        for (key, syn) in self.synapses.iter_mut() {
+            if !syn.is_inhibitory() || !fired.contains(&key.pre) {
+                continue;
+            }
+            let post = &self.neurons[key.post.index()];
+            let target = post.target_rate.unwrap_or(target_rate).max(1e-3);
+            let error = ((post.rate - target) / target).clamp(-1.0, 1.0);
+            syn.strength =
+                (syn.strength + self.lr_inh * modulation * error).clamp(0.0, self.inh_max);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// Un tour de conversation complet, tracé pour le journal d'expérience.
+
+use crate::brain::{Brain, NeuronId, SynapseKey, Vocabulary, without_commands};
+use crate::logging;
+use crate::modulator::Modulator;
+use crate::tokenizer::Tokenizer;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub type Word = String;
+
+// Ticks de propagation après le dernier mot d'une phrase
+const SETTLE_TICKS: usize = 1;
+
+/// Trace d'un tour de conversation : ce qui a été entendu, ce qui a spiké,
+/// ce qui a été répondu et l'état chimique avant/après.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interaction {
+    pub input: String,
+    pub tokens: Vec<Word>,
+    /// Spikes de la phase d'écoute (un ensemble trié par tick).
+    pub spikes: Vec<Vec<Word>>,
+    pub reply: String,
+    /// Spikes de la phase d'intégration de la réponse.
+    pub reply_spikes: Vec<Vec<Word>>,
+    pub modulator_before: Modulator,
+    pub modulator_after: Modulator,
+}
+
+/// Synapse mise en jeu par un tour, avec sa variation pendant ce tour.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TouchedSynapse {
+    pub pre: Word,
+    pub post: Word,
+    pub strength: f32,
+    pub delta: f32,
+    pub inhibitory: bool,
+}
+
+fn sorted_history(vocab: &Vocabulary, history: &[BTreeSet<NeuronId>]) -> Vec<Vec<Word>> {
+    history
+        .iter()
+        .map(|fired| {
+            let mut words: Vec<Word> = fired.iter().map(|&id| vocab.word(id).into()).collect();
+            words.sort();
+            words
+        })
+        .collect()
+}
+
+impl Brain {
+    /// Un tour complet de conversation : feedback implicite, spiking et
+    /// apprentissage sur l'entrée, génération de la réponse puis intégration
+    /// de cette réponse. Renvoie la réponse du bébé.
+    pub fn interact(&mut self, input: &str) -> String {
+        self.experience(input).reply
+    }
+
+    /// Comme [`Brain::interact`], mais renvoie la trace complète du tour.
+    pub fn experience(&mut self, input: &str) -> Interaction {
+        let modulator_before = self.modulator.clone();
+
+        // -------- feedback implicite sur ce que tu dis --------
+        self.update_modulator_from_feedback(input);
+
+        // -------- feedback explicite sur la réponse précédente --------
+        let last_reply_words = self.last_reply_words.clone();
+        self.apply_feedback(input, &last_reply_words);
+
+        let text = self.normalization.apply(&without_commands(input));
+        let words = self.tokenizer.tokenize(&text);
+
+        // -------- spiking / apprentissage --------
+
+        // On inclut les mots de sa dernière réponse dans l'apprentissage
+        // pour renforcer ce qui a été "utilisé" en conversation.
+        let mut seed_words = words.clone();
+        seed_words.extend(self.last_reply_words.clone());
+
+        // Un mot par tick, pour que la STDP voie l'ordre de la phrase
+        let spikes_history = self.run_sequence(&seed_words, SETTLE_TICKS);
+        self.learn_from_spikes(&spikes_history);
+        let spikes = sorted_history(&self.vocab, &spikes_history);
+
+        debug!(
+            target: logging::LEARNING,
+            "État du cerveau après apprentissage: {} neurones, {} synapses",
+            self.neurons.len(),
+            self.synapses.len()
+        );
+
+        // -------- génération réponse --------
+        let (meant, reply) = self.speak(&words);
+
+        // Inclure la réponse dans l'apprentissage : les mots qu'il voulait
+        // dire, pas leur babillage
+        let reply_words = self.tokenizer.tokenize(&meant);
+        self.tag_reply();
+        let spikes_history = self.run_sequence(&reply_words, SETTLE_TICKS);
+        self.learn_from_spikes(&spikes_history);
+        let reply_spikes = sorted_history(&self.vocab, &spikes_history);
+
+        self.last_reply_words = reply_words;
+        self.grow_older(
+            spikes
+                .iter()
+                .chain(&reply_spikes)
+                .map(|fired| fired.len() as u64)
+                .sum(),
+        );
+
+        debug!(
+            target: logging::LEARNING,
+            "État du cerveau après intégration de la réponse: {} neurones, {} synapses",
+            self.neurons.len(),
+            self.synapses.len()
+        );
+
+        if let Some(nt) = Modulator::detect_neurotransmitter(input) {
+            info!(target: logging::MODULATOR, "Neurotransmetteur détecté : {}", nt);
+            self.modulator.adjust_hormones_for_neurotransmitter(&nt);
+        }
+
+        Interaction {
+            input: input.to_string(),
+            tokens: words,
+            spikes,
+            reply,
+            reply_spikes,
+            modulator_before,
+            modulator_after: self.modulator.clone(),
+        }
+    }
+
+    /// Les `top` synapses les plus fortes entre mots entendus, dits ou qui
+    /// ont spiké pendant `interaction`, avec leur variation depuis `before`
+    /// (forces avant le tour ; une synapse absente y valait 0).
+    pub fn touched_synapses(
+        &self,
+        before: &BTreeMap<SynapseKey, f32>,
+        interaction: &Interaction,
+        top: usize,
+    ) -> Vec<TouchedSynapse> {
+        let active: BTreeSet<NeuronId> = interaction
+            .tokens
+            .iter()
+            .chain(interaction.spikes.iter().flatten())
+            .chain(interaction.reply_spikes.iter().flatten())
+            .chain(&self.last_reply_words)
+            .filter_map(|w| self.vocab.id(w))
+            .collect();
+        let mut touched: Vec<TouchedSynapse> = self
+            .synapses
+            .iter()
+            .filter(|(key, _)| active.contains(&key.pre) && active.contains(&key.post))
+            .map(|(key, syn)| TouchedSynapse {
+                pre: self.vocab.word(key.pre).to_string(),
+                post: self.vocab.word(key.post).to_string(),
+                strength: syn.strength,
+                delta: syn.strength - before.get(key).copied().unwrap_or(0.0),
+                inhibitory: syn.is_inhibitory(),
+            })
+            .collect();
+        touched.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+        touched.truncate(top);
+        touched
+    }
+}
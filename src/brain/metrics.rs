@@ -0,0 +1,305 @@
+// Télémétrie de la simulation.
+//
+// À chaque tick de `run_spiking` et à chaque passe de `learn_from_spikes`,
+// le cerveau met à jour des compteurs cumulés et, si un enregistreur est
+// branché, prend un échantillon : spikes, potentiels de membrane, chimie,
+// croissance du réseau et histogramme des poids. Les échantillons partent
+// en CSV ou en JSON-lines dans un fichier, pour tracer des courbes
+// d'apprentissage sur de longues conversations, et les derniers restent en
+// mémoire.
+//
+// Un observateur peut aussi suivre chaque tick en direct (le tableau de bord
+// s'en sert pour se redessiner pendant que le bébé réfléchit).
+//
+// Rien ici ne touche au hasard : brancher un enregistreur ne change pas la
+// simulation.
+
+use crate::brain::{Brain, NeuronId};
+use crate::logging;
+use crate::modulator::Modulator;
+use log::warn;
+use serde::Serialize;
+use std::collections::{BTreeSet, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Nombre de classes de l'histogramme des poids, sur `[0, exc_max]`.
+pub const HISTOGRAM_BINS: usize = 8;
+
+/// Échantillons gardés en mémoire par défaut.
+pub const DEFAULT_CAPACITY: usize = 256;
+
+/// Compteurs cumulés depuis la création (ou le chargement) du cerveau.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Counters {
+    pub ticks: u64,
+    pub spikes: u64,
+    pub learning_steps: u64,
+    pub neurons_created: u64,
+    pub synapses_created: u64,
+}
+
+/// Moment de la mesure.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    /// Fin d'un tick de [`Brain::run_spiking`].
+    Spiking,
+    /// Fin d'une passe de [`Brain::learn_from_spikes`].
+    Learning,
+}
+
+/// Un échantillon de télémétrie.
+#[derive(Clone, Debug, Serialize)]
+pub struct Sample {
+    pub phase: Phase,
+    pub turn: u64,
+    pub tick: u64,
+    /// Spikes du tick, ou de tout l'historique appris.
+    pub fired: usize,
+    pub v_mean: f32,
+    pub v_max: f32,
+    pub neurons: usize,
+    pub synapses: usize,
+    pub new_neurons: usize,
+    pub new_synapses: usize,
+    pub dopamine: f32,
+    pub stress: f32,
+    pub serotonin: f32,
+    pub noradrenaline: f32,
+    pub endorphins: f32,
+    /// Synapses par classe de poids ; la dernière classe inclut `exc_max`.
+    pub weights: [u32; HISTOGRAM_BINS],
+}
+
+/// Format du fichier de télémétrie.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MetricsFormat {
+    Csv,
+    #[default]
+    JsonLines,
+}
+
+impl MetricsFormat {
+    /// `.csv` pour du CSV, JSON-lines sinon.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some("csv") => Self::Csv,
+            _ => Self::JsonLines,
+        }
+    }
+}
+
+impl FromStr for MetricsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "jsonl" | "json" => Ok(Self::JsonLines),
+            other => Err(format!("format de télémétrie inconnu: {}", other)),
+        }
+    }
+}
+
+const CSV_COLUMNS: &str = "phase,turn,tick,fired,v_mean,v_max,neurons,synapses,\
+new_neurons,new_synapses,dopamine,stress,serotonin,noradrenaline,endorphins";
+
+impl Sample {
+    fn csv_header() -> String {
+        let bins: Vec<String> = (0..HISTOGRAM_BINS).map(|i| format!("w{}", i)).collect();
+        format!("{},{}", CSV_COLUMNS, bins.join(","))
+    }
+
+    fn csv_row(&self) -> String {
+        let phase = match self.phase {
+            Phase::Spiking => "spiking",
+            Phase::Learning => "learning",
+        };
+        let bins: Vec<String> = self.weights.iter().map(u32::to_string).collect();
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            phase,
+            self.turn,
+            self.tick,
+            self.fired,
+            self.v_mean,
+            self.v_max,
+            self.neurons,
+            self.synapses,
+            self.new_neurons,
+            self.new_synapses,
+            self.dopamine,
+            self.stress,
+            self.serotonin,
+            self.noradrenaline,
+            self.endorphins,
+            bins.join(",")
+        )
+    }
+}
+
+/// Enregistreur d'échantillons : les derniers en mémoire, tous dans un
+/// fichier si on lui en donne un.
+pub struct MetricsRecorder {
+    pub capacity: usize,
+    recent: VecDeque<Sample>,
+    file: Option<(LineWriter<File>, MetricsFormat)>,
+}
+
+impl MetricsRecorder {
+    /// Garde en mémoire les `capacity` derniers échantillons.
+    pub fn in_memory(capacity: usize) -> Self {
+        Self {
+            capacity,
+            recent: VecDeque::with_capacity(capacity),
+            file: None,
+        }
+    }
+
+    /// Écrit aussi chaque échantillon dans `path`, à la suite. L'en-tête CSV
+    /// n'est écrit que dans un fichier vide.
+    pub fn to_file(path: impl AsRef<Path>, format: MetricsFormat) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let empty = file.metadata()?.len() == 0;
+        let mut file = LineWriter::new(file);
+        if empty && format == MetricsFormat::Csv {
+            writeln!(file, "{}", Sample::csv_header())?;
+        }
+        let mut recorder = Self::in_memory(DEFAULT_CAPACITY);
+        recorder.file = Some((file, format));
+        Ok(recorder)
+    }
+
+    /// Les derniers échantillons, du plus ancien au plus récent.
+    pub fn recent(&self) -> impl Iterator<Item = &Sample> {
+        self.recent.iter()
+    }
+
+    fn push(&mut self, sample: Sample) -> io::Result<()> {
+        let written = match &mut self.file {
+            Some((file, MetricsFormat::Csv)) => writeln!(file, "{}", sample.csv_row()),
+            Some((file, MetricsFormat::JsonLines)) => serde_json::to_string(&sample)
+                .map_err(io::Error::other)
+                .and_then(|line| writeln!(file, "{}", line)),
+            None => Ok(()),
+        };
+        if self.capacity > 0 {
+            if self.recent.len() == self.capacity {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(sample);
+        }
+        written
+    }
+}
+
+/// Appelé à la fin de chaque tick avec les mots qui ont spiké et la chimie.
+pub type TickObserver = Box<dyn FnMut(&[&str], &Modulator) + Send>;
+
+/// Télémétrie d'un cerveau (non sauvegardée avec lui).
+#[derive(Default)]
+pub struct Metrics {
+    pub counters: Counters,
+    /// Échantillonnage détaillé ; `None` pour ne tenir que les compteurs.
+    pub recorder: Option<MetricsRecorder>,
+    /// Suivi en direct des ticks ; `None` par défaut.
+    pub on_tick: Option<TickObserver>,
+}
+
+impl Brain {
+    /// Note une mesure : compteurs toujours, échantillon si un enregistreur
+    /// est branché.
+    pub(crate) fn record_metrics(
+        &mut self,
+        phase: Phase,
+        fired: usize,
+        new_neurons: usize,
+        new_synapses: usize,
+    ) {
+        let counters = &mut self.metrics.counters;
+        match phase {
+            Phase::Spiking => {
+                counters.ticks += 1;
+                counters.spikes += fired as u64;
+            }
+            Phase::Learning => counters.learning_steps += 1,
+        }
+        counters.neurons_created += new_neurons as u64;
+        counters.synapses_created += new_synapses as u64;
+
+        if self.metrics.recorder.is_none() {
+            return;
+        }
+        let sample = self.metrics_sample(phase, fired, new_neurons, new_synapses);
+        if let Some(recorder) = self.metrics.recorder.as_mut()
+            && let Err(e) = recorder.push(sample)
+        {
+            warn!(target: logging::PERSIST, "Télémétrie non écrite: {}", e);
+        }
+    }
+
+    /// Prévient l'observateur de ticks, s'il y en a un.
+    pub(crate) fn observe_tick(&mut self, fired: &BTreeSet<NeuronId>) {
+        if let Some(observer) = self.metrics.on_tick.as_mut() {
+            observer(&self.vocab.resolve(fired.iter().copied()), &self.modulator);
+        }
+    }
+
+    /// Compte ce qui a poussé hors de la simulation (feedback, récompense),
+    /// sans échantillon.
+    pub(crate) fn record_growth(&mut self, new_neurons: usize, new_synapses: usize) {
+        let counters = &mut self.metrics.counters;
+        counters.neurons_created += new_neurons as u64;
+        counters.synapses_created += new_synapses as u64;
+    }
+
+    fn metrics_sample(
+        &self,
+        phase: Phase,
+        fired: usize,
+        new_neurons: usize,
+        new_synapses: usize,
+    ) -> Sample {
+        let (v_sum, v_max) = self
+            .neurons
+            .iter()
+            .fold((0.0, f32::NEG_INFINITY), |(sum, max), n| {
+                (sum + n.v, max.max(n.v))
+            });
+        let v_mean = if self.neurons.is_empty() {
+            0.0
+        } else {
+            v_sum / self.neurons.len() as f32
+        };
+
+        let mut weights = [0; HISTOGRAM_BINS];
+        let width = self.exc_max.max(f32::EPSILON) / HISTOGRAM_BINS as f32;
+        for syn in self.synapses.values() {
+            let bin = (syn.strength.max(0.0) / width) as usize;
+            weights[bin.min(HISTOGRAM_BINS - 1)] += 1;
+        }
+
+        let m = &self.modulator;
+        Sample {
+            phase,
+            turn: self.age.turns,
+            tick: self.metrics.counters.ticks,
+            fired,
+            v_mean,
+            v_max: if self.neurons.is_empty() { 0.0 } else { v_max },
+            neurons: self.neurons.len(),
+            synapses: self.synapses.len(),
+            new_neurons,
+            new_synapses,
+            dopamine: m.dopamine,
+            stress: m.stress,
+            serotonin: m.serotonin,
+            noradrenaline: m.noradrenaline,
+            endorphins: m.endorphins,
+            weights,
+        }
+    }
+}
@@ -1,7 +1,29 @@
+pub mod development;
+pub mod event_driven;
+pub mod feedback;
+pub mod generation;
+pub mod homeostasis;
+pub mod inhibition;
+pub mod interaction;
+pub mod metrics;
 pub mod neurons;
 pub mod orchestrator;
+pub mod parallel;
+pub mod plasticity;
+pub mod reward;
 pub mod synapses;
+pub mod vocabulary;
 
-pub use neurons::{ensure_neuron, inject_input};
+pub use development::{Age, DevelopmentParams, Milestone, Stage};
+pub use event_driven::SimulationMode;
+pub use feedback::{feedback_score, without_commands};
+pub use generation::GenerationParams;
+pub use homeostasis::HomeostasisParams;
+pub use interaction::{Interaction, TouchedSynapse};
+pub use metrics::{Counters, Metrics, MetricsFormat, MetricsRecorder, TickObserver};
+pub use neurons::{HEARD_POTENTIAL, ensure_neuron, hear_input, inject_input};
 pub use orchestrator::Brain;
-pub use synapses::ensure_synapse;
+pub use plasticity::StdpParams;
+pub use reward::RewardParams;
+pub use synapses::{SynapseKey, SynapseStore, ensure_synapse};
+pub use vocabulary::{NeuronId, Vocabulary};
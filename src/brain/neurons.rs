@@ -1,25 +1,69 @@
+use crate::brain::{NeuronId, Vocabulary};
 use crate::composition::Composition;
 use crate::neuron::Neuron;
-use std::collections::HashMap;
 
 pub type Word = String;
 
-pub fn ensure_neuron(neurons: &mut HashMap<Word, Neuron>, w: &str, composition: Composition) {
-    neurons
-        .entry(w.to_string())
-        .or_insert_with(|| Neuron::new(1.0, composition));
+/// Neurone du mot `w`, créé si besoin. `neurons` est indexé par les
+/// identifiants de `vocab`.
+pub fn ensure_neuron(
+    vocab: &mut Vocabulary,
+    neurons: &mut Vec<Neuron>,
+    w: &str,
+    composition: Composition,
+) -> NeuronId {
+    let id = vocab.intern(w);
+    if neurons.len() <= id.index() {
+        neurons.resize_with(id.index() + 1, || Neuron::new(1.0, composition.clone()));
+    }
+    id
+}
+
+/// Exécute `f` avec le seuil de `n` décalé de la dérive commune `drift`
+/// (voir [`crate::Brain::threshold_drift`]), puis rétablit le seuil exact.
+pub(crate) fn with_drift<R>(n: &mut Neuron, drift: f32, f: impl FnOnce(&mut Neuron) -> R) -> R {
+    let threshold = n.threshold;
+    n.threshold += drift;
+    let result = f(n);
+    n.threshold = threshold;
+    result
 }
 
+/// Stimule les neurones des mots entendus et renvoie leurs identifiants.
 pub fn inject_input(
-    neurons: &mut HashMap<Word, Neuron>,
+    vocab: &mut Vocabulary,
+    neurons: &mut Vec<Neuron>,
     active_words: &[Word],
-) -> std::collections::HashSet<Word> {
-    let spikes = std::collections::HashSet::new();
-    for w in active_words {
-        ensure_neuron(neurons, w, Composition::new(vec![])); // Composition par défaut
-        if let Some(n) = neurons.get_mut(w) {
-            n.v += 0.6;
-        }
-    }
-    spikes
+) -> Vec<NeuronId> {
+    active_words
+        .iter()
+        .map(|w| {
+            let id = ensure_neuron(vocab, neurons, w, Composition::new(vec![])); // Composition par défaut
+            neurons[id.index()].v += 0.6;
+            id
+        })
+        .collect()
+}
+
+/// Potentiel d'un neurone dont on entend le mot : assez pour spiker au tick
+/// où le mot arrive, sauf période réfractaire ou seuil remonté par
+/// l'homéostasie.
+pub const HEARD_POTENTIAL: f32 = 1.5;
+
+/// Fait entendre des mots : leurs neurones sont portés à
+/// [`HEARD_POTENTIAL`]. Renvoie leurs identifiants.
+pub fn hear_input(
+    vocab: &mut Vocabulary,
+    neurons: &mut Vec<Neuron>,
+    heard_words: &[Word],
+) -> Vec<NeuronId> {
+    heard_words
+        .iter()
+        .map(|w| {
+            let id = ensure_neuron(vocab, neurons, w, Composition::default());
+            let n = &mut neurons[id.index()];
+            n.v = n.v.max(HEARD_POTENTIAL);
+            id
+        })
+        .collect()
 }
@@ -3,20 +3,52 @@
 // Suppression des duplications et intégration des méthodes manquantes
 // Uniformisation des commentaires et ajustements pour la modularité
 
-use crate::brain::{inject_input, neurons, synapses};
+use crate::brain::event_driven::EventQueue;
+use crate::brain::metrics::Phase;
+use crate::brain::neurons::with_drift;
+use crate::brain::{
+    Age, DevelopmentParams, GenerationParams, HomeostasisParams, Metrics, NeuronId, RewardParams,
+    SimulationMode, StdpParams, SynapseKey, SynapseStore, Vocabulary, hear_input, inject_input,
+    neurons, synapses,
+};
 use crate::composition::Composition;
+use crate::logging;
 use crate::neuron::Neuron;
+use crate::neuron_model::{NeuronModel, NeuronModelKind};
 use crate::neurotransmitter;
-use crate::synapse::Synapse;
-use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use crate::normalize::Normalization;
+use crate::tokenizer::TokenizerKind;
+use log::{debug, trace};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub type Word = String;
 
-#[derive(Serialize, Deserialize)]
+/// Facteurs globaux du tick `t` : (oscillation, rythme circadien).
+pub(crate) fn tick_factors(t: usize) -> (f32, f32) {
+    let oscillation_factor = (t as f32 * 0.1).sin();
+    let circadian_factor = ((t as f32 / 100.0).sin() + 1.0) / 2.0; // Oscillation lente
+    (oscillation_factor, circadian_factor)
+}
+
+/// Le cerveau du bébé : neurones-mots, synapses entre mots et chimie globale.
+/// Voir [`crate::persist`] pour la sauvegarde.
+///
+/// Les mots sont internés dans [`Brain::vocab`] : neurones et synapses sont
+/// désignés par des [`NeuronId`], les chaînes ne servent qu'aux bords
+/// (entrée, réponse, sauvegarde).
+///
+/// Toute la simulation est déterministe : les neurones et synapses sont
+/// parcourus dans l'ordre des identifiants et le hasard passe par
+/// [`Brain::rng`].
+/// Deux cerveaux créés avec la même graine et nourris des mêmes entrées
+/// produisent des snapshots identiques à l'octet près.
 pub struct Brain {
-    pub neurons: HashMap<Word, Neuron>,
-    pub synapses: HashMap<(Word, Word), Synapse>,
+    pub vocab: Vocabulary,
+    /// Indexé par [`NeuronId`].
+    pub neurons: Vec<Neuron>,
+    pub synapses: SynapseStore,
     pub modulator: crate::Modulator,
 
     // hyperparams
@@ -25,153 +57,233 @@ pub struct Brain {
     pub exc_max: f32,
     pub inh_max: f32,
     pub forget: f32,
+    pub stdp: StdpParams,
+    pub homeostasis: HomeostasisParams,
+    pub reward: RewardParams,
+    pub generation: GenerationParams,
+    pub development: DevelopmentParams,
+    /// Découpage du texte en unités (mots, syllabes, n-grammes).
+    pub tokenizer: TokenizerKind,
+    /// Mise en forme du texte avant découpage.
+    pub normalization: Normalization,
+    pub simulation: SimulationMode,
+    /// Dynamique des neurones qui n'en imposent pas une autre.
+    pub neuron_model: NeuronModelKind,
+
+    /// Âge du bébé, qui fixe son stade de langage.
+    pub age: Age,
+
+    /// Mots de la dernière réponse, réinjectés au tour suivant.
+    pub last_reply_words: Vec<Word>,
+    /// Synapses qui ont produit la dernière réponse, cibles du feedback.
+    pub last_reply_synapses: Vec<SynapseKey>,
+
+    /// Compteurs et échantillons de télémétrie (non sauvegardés).
+    pub metrics: Metrics,
+
+    /// Dérive des seuils commune à tous les neurones, accumulée tick après
+    /// tick et reportée sur chacun au prochain [`Brain::learn_from_spikes`] :
+    /// un tick n'a pas à toucher tous les neurones. Le seuil effectif d'un
+    /// neurone est donné par [`Brain::threshold`].
+    pub threshold_drift: f32,
+    /// Neurones suivis par la simulation événementielle, gardés d'un run à
+    /// l'autre (non sauvegardés).
+    pub(crate) events: Option<EventQueue>,
+
+    /// Graine d'origine du générateur aléatoire.
+    pub seed: u64,
+    /// Seule source de hasard de la simulation (son état est sauvegardé).
+    pub rng: ChaCha8Rng,
+}
+
+impl Default for Brain {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Brain {
+    /// Crée un cerveau neuf, pré-câblé avec quelques mots de base, avec une
+    /// graine aléatoire.
     pub fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    /// Comme [`Brain::new`], mais reproductible : même graine, même bébé.
+    pub fn with_seed(seed: u64) -> Self {
         let mut brain = Self {
-            neurons: HashMap::new(),
-            synapses: HashMap::new(),
+            vocab: Vocabulary::new(),
+            neurons: Vec::new(),
+            synapses: SynapseStore::new(),
             modulator: crate::Modulator::new(),
             lr_exc: 0.05,
             lr_inh: 0.03,
             exc_max: 3.0,
             inh_max: 2.0,
             forget: 0.999,
+            stdp: StdpParams::default(),
+            homeostasis: HomeostasisParams::default(),
+            reward: RewardParams::default(),
+            generation: GenerationParams::default(),
+            development: DevelopmentParams::default(),
+            tokenizer: TokenizerKind::default(),
+            normalization: Normalization::default(),
+            simulation: SimulationMode::default(),
+            neuron_model: NeuronModelKind::default(),
+            age: Age::default(),
+            last_reply_words: Vec::new(),
+            last_reply_synapses: Vec::new(),
+            metrics: Metrics::default(),
+            threshold_drift: 0.0,
+            events: None,
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
         };
 
         // Stimulation initiale : ajouter des mots et connexions de base
-        let initial_words = vec!["maman", "papa", "bébé", "amour", "calme", "joie"];
-        for word in &initial_words {
-            neurons::ensure_neuron(&mut brain.neurons, word, Composition::default()); // Composition par défaut
-        }
-        for i in 0..initial_words.len() {
-            for j in i + 1..initial_words.len() {
-                synapses::ensure_synapse(
-                    &mut brain.synapses,
-                    initial_words[i],
-                    initial_words[j],
-                    neurotransmitter::Neurotransmitter::Glutamate,
-                );
+        let initial_words = ["maman", "papa", "bébé", "amour", "calme", "joie"];
+        let initial_ids: Vec<NeuronId> = initial_words
+            .iter()
+            .map(|word| {
+                neurons::ensure_neuron(
+                    &mut brain.vocab,
+                    &mut brain.neurons,
+                    word,
+                    Composition::default(), // Composition par défaut
+                )
+            })
+            .collect();
+        // Câblage de base symétrique : une synapse dans chaque sens
+        for &pre in &initial_ids {
+            for &post in &initial_ids {
+                if pre != post {
+                    synapses::ensure_synapse(
+                        &mut brain.synapses,
+                        pre,
+                        post,
+                        neurotransmitter::Neurotransmitter::Glutamate,
+                    );
+                }
             }
         }
 
         brain
     }
 
-    pub fn run_spiking(&mut self, seed_words: &[Word], max_ticks: usize) -> Vec<HashSet<Word>> {
-        println!(
-            "[Brain] Début de run_spiking avec seed_words: {:?}, max_ticks: {}",
-            seed_words, max_ticks
+    /// Propage l'activité à partir des mots-graines pendant au plus
+    /// `max_ticks` ticks et renvoie, pour chaque tick, les neurones qui ont
+    /// émis un spike.
+    pub fn run_spiking(
+        &mut self,
+        seed_words: &[Word],
+        max_ticks: usize,
+    ) -> Vec<BTreeSet<NeuronId>> {
+        self.run_inputs(seed_words, &[seed_words], inject_input, max_ticks)
+    }
+
+    /// Comme [`Brain::run_spiking`], mais les mots sont entendus un par tick,
+    /// dans l'ordre de la phrase (voir [`hear_input`]) : la STDP voit la
+    /// séquence. La propagation continue au plus `settle` ticks après le
+    /// dernier mot.
+    pub fn run_sequence(&mut self, words: &[Word], settle: usize) -> Vec<BTreeSet<NeuronId>> {
+        let inputs: Vec<&[Word]> = words.chunks(1).collect();
+        self.run_inputs(words, &inputs, hear_input, words.len() + settle)
+    }
+
+    // `inputs[t]` est stimulé par `stimulate` au début du tick `t`
+    fn run_inputs(
+        &mut self,
+        seed_words: &[Word],
+        inputs: &[&[Word]],
+        stimulate: fn(&mut Vocabulary, &mut Vec<Neuron>, &[Word]) -> Vec<NeuronId>,
+        max_ticks: usize,
+    ) -> Vec<BTreeSet<NeuronId>> {
+        debug!(
+            target: logging::SPIKING,
+            "Début de run_spiking avec seed_words: {:?}, max_ticks: {}", seed_words, max_ticks
         );
 
-        for i in 0..seed_words.len() {
-            neurons::ensure_neuron(&mut self.neurons, &seed_words[i], Composition::default()); // Composition par défaut
-            for j in i + 1..seed_words.len() {
-                neurons::ensure_neuron(&mut self.neurons, &seed_words[j], Composition::default()); // Composition par défaut
+        let mut sizes = (self.neurons.len(), self.synapses.len());
+
+        let seed_ids: Vec<NeuronId> = seed_words
+            .iter()
+            .map(|w| {
+                neurons::ensure_neuron(
+                    &mut self.vocab,
+                    &mut self.neurons,
+                    w,
+                    Composition::default(), // Composition par défaut
+                )
+            })
+            .collect();
+        for (i, &pre) in seed_ids.iter().enumerate() {
+            for &post in &seed_ids[i + 1..] {
+                // Orientée dans l'ordre de la phrase
                 synapses::ensure_synapse(
                     &mut self.synapses,
-                    &seed_words[i],
-                    &seed_words[j],
+                    pre,
+                    post,
                     neurotransmitter::Neurotransmitter::Glutamate,
                 );
             }
         }
 
-        inject_input(&mut self.neurons, seed_words);
-
-        let mut history: Vec<HashSet<Word>> = Vec::with_capacity(max_ticks);
+        let mut history: Vec<BTreeSet<NeuronId>> = Vec::with_capacity(max_ticks);
         let mut last_fired_count = 0;
 
-        for t in 0..max_ticks {
-            let mut fired: HashSet<Word> = HashSet::new();
-
-            let keys: Vec<Word> = self.neurons.keys().cloned().collect();
-            for w in keys.iter() {
-                let refractory = self.neurons[w].refractory;
-                if refractory > 0 {
-                    continue;
-                }
-
-                let mut input_sum = 0.0;
-
-                for ((a, b), syn) in self.synapses.iter() {
-                    if a == w || b == w {
-                        let other = if a == w { b } else { a };
-                        let other_v = self.neurons.get(other).map(|n| n.v).unwrap_or(0.0);
-                        let other_spike_like = if other_v > 0.9 { 1.0 } else { 0.0 };
-                        if syn.is_excitatory() {
-                            input_sum += other_spike_like * syn.strength;
-                        } else if syn.is_inhibitory() {
-                            input_sum -= other_spike_like * syn.strength;
-                        }
-                    }
-                }
-
-                let n = self.neurons.get_mut(w).unwrap();
-                n.v = n.v * (1.0 - n.leak) + input_sum;
+        // Les neurones suivis au run précédent le sont encore ; un run dense
+        // les rend caducs
+        let mut sparse = match self.simulation {
+            SimulationMode::Dense | SimulationMode::Parallel => {
+                self.events = None;
+                None
             }
-
-            for w in self.neurons.keys().cloned().collect::<Vec<_>>() {
-                let (th, refra, v_now) = {
-                    let n = &self.neurons[&w];
-                    (n.threshold, n.refractory, n.v)
-                };
-
-                if refra == 0 && v_now > th {
-                    fired.insert(w.clone());
-                    let n = self.neurons.get_mut(&w).unwrap();
-                    n.v = 0.0;
-                    n.refractory = 2;
-                    n.fired_count += 1;
-                }
+            SimulationMode::EventDriven => {
+                Some(self.events.take().unwrap_or_else(|| EventQueue::new(self)))
             }
+        };
 
-            for n in self.neurons.values_mut() {
-                if n.refractory > 0 {
-                    n.refractory -= 1;
+        for t in 0..max_ticks {
+            if let Some(words) = inputs.get(t) {
+                if let Some(queue) = sparse.as_mut() {
+                    self.wake(queue, words, t);
                 }
+                stimulate(&mut self.vocab, &mut self.neurons, words);
             }
 
-            // Limiter les activations excessives
-            for n in self.neurons.values_mut() {
-                if n.v > 1.5 {
-                    // Seuil pour limiter les activations excessives
-                    n.v = 1.5;
-                }
-            }
+            let fired = match sparse.as_mut() {
+                Some(queue) => self.tick_event_driven(queue, t),
+                None if self.simulation == SimulationMode::Parallel => self.tick_parallel(t),
+                None => self.tick_dense(t),
+            };
 
             // Renforcer les connexions pertinentes
-            for ((a, b), syn) in self.synapses.iter_mut() {
-                if fired.contains(a) && fired.contains(b) {
+            let co_fired: Vec<SynapseKey> = fired
+                .iter()
+                .flat_map(|&pre| {
+                    self.synapses
+                        .successors(pre)
+                        .filter(|post| fired.contains(post))
+                        .map(move |post| SynapseKey::new(pre, post))
+                })
+                .collect();
+            for key in co_fired {
+                if let Some(syn) = self.synapses.get_mut(&key)
+                    && syn.is_excitatory()
+                {
                     syn.strength += self.lr_exc * self.modulator.dopamine.max(0.1);
                 }
             }
 
-            // Simulation des oscillations neuronales
-            let oscillation_factor = (t as f32 * 0.1).sin();
-            for n in self.neurons.values_mut() {
-                n.v += oscillation_factor * 0.05; // Ajout d'une oscillation légère
-            }
-
-            // Ajustement dynamique des seuils des neurones
-            for n in self.neurons.values_mut() {
-                n.threshold += oscillation_factor * 0.01; // Ajustement dynamique
-            }
-
-            // Simulation des rythmes circadiens
-            let circadian_factor = ((t as f32 / 100.0).sin() + 1.0) / 2.0; // Oscillation lente
-            for n in self.neurons.values_mut() {
-                n.v *= circadian_factor; // Modulation lente
-            }
-
             // Ajustement des hormones en fonction des rythmes circadiens
+            let (_, circadian_factor) = tick_factors(t);
             self.modulator.dopamine *= circadian_factor;
             self.modulator.serotonin *= circadian_factor;
             self.modulator.stress *= 1.0 - circadian_factor;
 
-            println!(
-                "[Brain] Modulateur après rythmes circadiens: dopamine = {:.2}, stress = {:.2}, serotonin = {:.2}, noradrenaline = {:.2}, endorphins = {:.2}",
+            trace!(
+                target: logging::MODULATOR,
+                "Modulateur après rythmes circadiens: dopamine = {:.2}, stress = {:.2}, serotonin = {:.2}, noradrenaline = {:.2}, endorphins = {:.2}",
                 self.modulator.dopamine,
                 self.modulator.stress,
                 self.modulator.serotonin,
@@ -179,66 +291,191 @@ impl Brain {
                 self.modulator.endorphins
             );
 
-            println!("[Brain] Tick {}: fired neurons = {:?}", t, fired);
+            debug!(
+                target: logging::SPIKING,
+                "Tick {}: fired neurons = {:?}",
+                t,
+                self.vocab.resolve(fired.iter().copied())
+            );
+            self.observe_tick(&fired);
             history.push(fired.clone());
 
-            // Arrêter si aucune activité n'est détectée
-            if fired.is_empty() && last_fired_count == 0 {
-                println!("[Brain] Aucun spike détecté, arrêt anticipé au tick {}", t);
+            let (neurons, synapses) = sizes;
+            sizes = (self.neurons.len(), self.synapses.len());
+            self.record_metrics(
+                Phase::Spiking,
+                fired.len(),
+                sizes.0.saturating_sub(neurons),
+                sizes.1.saturating_sub(synapses),
+            );
+
+            // Arrêter si aucune activité n'est détectée et plus rien n'arrive
+            if fired.is_empty() && last_fired_count == 0 && t + 1 >= inputs.len() {
+                debug!(
+                    target: logging::SPIKING,
+                    "Aucun spike détecté, arrêt anticipé au tick {}", t
+                );
                 break;
             }
 
             last_fired_count = fired.len();
         }
 
-        println!("[Brain] Fin de run_spiking");
+        if let Some(queue) = sparse {
+            self.finish_event_driven(queue);
+        }
+
+        trace!(target: logging::SPIKING, "Fin de run_spiking");
         history
     }
 
-    pub fn learn_from_spikes(&mut self, spikes_history: &[HashSet<Word>]) {
-        println!(
-            "[Brain] Début de learn_from_spikes avec spikes_history de longueur: {}",
-            spikes_history.len()
-        );
+    /// Entrées synaptiques reçues par chaque cible depuis les neurones
+    /// `active` : on suit leurs seules synapses sortantes, au lieu de
+    /// parcourir toutes les synapses pour chaque neurone.
+    pub(crate) fn synaptic_inputs<I>(&self, active: I) -> BTreeMap<NeuronId, f32>
+    where
+        I: IntoIterator<Item = NeuronId>,
+    {
+        let mut inputs: BTreeMap<NeuronId, f32> = BTreeMap::new();
+        for pre in active {
+            for (post, syn) in self.synapses.outgoing(pre) {
+                let input = inputs.entry(post).or_default();
+                if syn.is_excitatory() {
+                    *input += syn.strength;
+                } else if syn.is_inhibitory() {
+                    *input -= syn.strength;
+                }
+            }
+        }
+        inputs
+    }
 
-        for fired in spikes_history.iter() {
-            let fired_vec: Vec<_> = fired.iter().cloned().collect();
-            for i in 0..fired_vec.len() {
-                for j in i + 1..fired_vec.len() {
-                    let a = &fired_vec[i];
-                    let b = &fired_vec[j];
-                    synapses::ensure_synapse(
-                        &mut self.synapses,
-                        a,
-                        b,
-                        neurotransmitter::Neurotransmitter::Glutamate,
-                    );
-                    let key = (a.clone(), b.clone());
-                    let syn = self.synapses.get_mut(&key).unwrap();
+    /// Seuil effectif du neurone `id`, dérive commune comprise (voir
+    /// [`Brain::threshold_drift`]).
+    pub fn threshold(&self, id: NeuronId) -> f32 {
+        self.neurons[id.index()].threshold + self.threshold_drift
+    }
 
-                    let dop = self.modulator.dopamine.max(0.1);
-                    syn.strength += self.lr_exc * dop;
-                }
+    // Reporte la dérive commune sur le seuil de chaque neurone
+    fn settle_threshold_drift(&mut self) {
+        if self.threshold_drift != 0.0 {
+            for n in self.neurons.iter_mut() {
+                n.threshold += self.threshold_drift;
             }
+            self.threshold_drift = 0.0;
         }
+    }
+
+    // Un tick dense : tous les neurones sont mis à jour.
+    pub(crate) fn tick_dense(&mut self, t: usize) -> BTreeSet<NeuronId> {
+        let mut fired: BTreeSet<NeuronId> = BTreeSet::new();
+
+        // Actifs = v > 0.9 en début de tick
+        let active: Vec<NeuronId> = self
+            .vocab
+            .iter()
+            .map(|(id, _)| id)
+            .filter(|id| self.neurons[id.index()].v > 0.9)
+            .collect();
+        let inputs = self.synaptic_inputs(active);
+
+        let default_model = self.neuron_model;
+        let drift = self.threshold_drift;
+        for (id, n) in self.vocab.iter().map(|(id, _)| id).zip(&mut self.neurons) {
+            if n.refractory > 0 {
+                continue;
+            }
+            let input_sum = inputs.get(&id).copied().unwrap_or(0.0);
+            let model = n.model.unwrap_or(default_model);
+            with_drift(n, drift, |n| model.integrate(n, input_sum * n.gain));
+        }
+
+        for (id, n) in self.vocab.iter().map(|(id, _)| id).zip(&mut self.neurons) {
+            let model = n.model.unwrap_or(default_model);
+            if n.refractory == 0 && with_drift(n, drift, |n| model.fires(n)) {
+                fired.insert(id);
+                with_drift(n, drift, |n| model.reset(n));
+                n.refractory = model.refractory();
+                n.fired_count += 1;
+            }
+        }
+
+        for n in self.neurons.iter_mut() {
+            if n.refractory > 0 {
+                n.refractory -= 1;
+            }
+        }
+
+        // Limiter les activations excessives
+        for n in self.neurons.iter_mut() {
+            if n.v > 1.5 {
+                // Seuil pour limiter les activations excessives
+                n.v = 1.5;
+            }
+        }
+
+        let (oscillation_factor, circadian_factor) = tick_factors(t);
+
+        // Simulation des oscillations neuronales
+        for n in self.neurons.iter_mut() {
+            n.v += oscillation_factor * 0.05; // Ajout d'une oscillation légère
+        }
+
+        // Ajustement dynamique des seuils des neurones
+        self.threshold_drift += oscillation_factor * 0.01;
+
+        // Simulation des rythmes circadiens
+        for n in self.neurons.iter_mut() {
+            n.v *= circadian_factor; // Modulation lente
+        }
+
+        fired
+    }
+
+    /// Apprentissage hebbien (et homéostasie) à partir d'un historique de
+    /// spikes produit par [`Brain::run_spiking`].
+    pub fn learn_from_spikes(&mut self, spikes_history: &[BTreeSet<NeuronId>]) {
+        trace!(
+            target: logging::LEARNING,
+            "Début de learn_from_spikes avec spikes_history de longueur: {}",
+            spikes_history.len()
+        );
+
+        // La dérive des seuils accumulée pendant les runs rejoint chaque neurone
+        self.settle_threshold_drift();
 
-        if self.modulator.stress > 0.2 {
-            let last_fired = spikes_history.last().cloned().unwrap_or_default();
-            let fired_vec: Vec<_> = last_fired.iter().cloned().collect();
-            for i in 0..fired_vec.len() {
-                for j in i + 1..fired_vec.len() {
-                    let a = &fired_vec[i];
-                    let b = &fired_vec[j];
-                    let key = (a.clone(), b.clone());
-                    if let Some(syn) = self.synapses.get_mut(&key) {
-                        syn.strength += self.lr_inh * self.modulator.stress;
+        let (neurons, synapses) = (self.neurons.len(), self.synapses.len());
+        self.decay_eligibility(spikes_history.len());
+
+        // Ordre des spikes : STDP entre ticks successifs
+        self.apply_stdp(spikes_history);
+
+        // Co-activation dans le même tick : pas d'ordre connu, on renforce
+        // les synapses excitatrices existantes dans les deux sens
+        for fired in spikes_history.iter() {
+            for a in fired {
+                for b in fired {
+                    let key = SynapseKey::new(*a, *b);
+                    if a != b
+                        && let Some(syn) = self.synapses.get_mut(&key)
+                        && syn.is_excitatory()
+                    {
+                        let dop = self.modulator.dopamine.max(0.1);
+                        syn.strength += self.lr_exc * dop;
+                        syn.add_eligibility(0.5); // Ordre inconnu : trace plus faible
                     }
                 }
             }
         }
 
+        // Neurones dopaminergiques : récompense locale
+        self.release_from_dopaminergic(spikes_history);
+
+        // Compétition et stress : synapses inhibitrices
+        self.learn_inhibition(spikes_history);
+
         // Limiter les activations excessives
-        for n in self.neurons.values_mut() {
+        for n in self.neurons.iter_mut() {
             if n.v > 1.5 {
                 // Seuil pour limiter les activations excessives
                 n.v = 1.5;
@@ -247,56 +484,53 @@ impl Brain {
 
         // Renforcer les connexions pertinentes
         for fired in spikes_history.iter() {
-            let fired_vec: Vec<_> = fired.iter().cloned().collect();
-            for i in 0..fired_vec.len() {
-                for j in i + 1..fired_vec.len() {
-                    let a = &fired_vec[i];
-                    let b = &fired_vec[j];
-                    let key = (a.clone(), b.clone());
-                    if let Some(syn) = self.synapses.get_mut(&key) {
+            for a in fired {
+                for b in fired {
+                    let key = SynapseKey::new(*a, *b);
+                    if a == b {
+                        continue;
+                    }
+                    if let Some(syn) = self.synapses.get_mut(&key)
+                        && syn.is_excitatory()
+                    {
                         let modulation = self
                             .modulator
                             .modulate_neurotransmitter(syn.neurotransmitter.to_string().as_str());
                         syn.strength += self.lr_exc * modulation;
-                        syn.strength = (syn.strength * self.forget).clamp(0.0, self.inh_max); // Décroissance de l'inhibition
+                        syn.strength = (syn.strength * self.forget).clamp(0.0, self.exc_max);
                     }
                 }
             }
         }
 
+        // Oubli, chaque type dans ses propres bornes
         for syn in self.synapses.values_mut() {
-            syn.strength = (syn.strength * self.forget).clamp(0.0, self.exc_max);
-            syn.strength = (syn.strength * self.forget).clamp(0.0, self.inh_max);
-        }
-
-        for n in self.neurons.values_mut() {
-            if n.fired_count > 200 {
-                n.threshold += 0.02;
-                n.fired_count = 0;
-            } else if n.fired_count == 0 && n.threshold > 0.6 {
-                n.threshold -= 0.001;
-            }
-        }
-
-        // Ajuster les seuils des neurones en fonction de leur type
-        for n in self.neurons.values_mut() {
-            let is_excitatory = n
-                .composition
-                .iter()
-                .any(|m| m.role.to_lowercase() == "excitatory");
-            if is_excitatory {
-                n.threshold = (n.threshold * 0.98).max(0.8); // Réduction progressive
+            let max = if syn.is_inhibitory() {
+                self.inh_max
             } else {
-                n.threshold = (n.threshold * 1.02).min(1.2); // Augmentation progressive
-            }
+                self.exc_max
+            };
+            syn.strength = (syn.strength * self.forget).clamp(0.0, max);
         }
 
-        println!("[Brain] Fin de learn_from_spikes");
+        // Seuils, gains et synapses entrantes vers le taux cible
+        self.apply_homeostasis(spikes_history);
+
+        trace!(target: logging::LEARNING, "Fin de learn_from_spikes");
         self.modulator.decay();
+
+        let fired = spikes_history.iter().map(BTreeSet::len).sum();
+        self.record_metrics(
+            Phase::Learning,
+            fired,
+            self.neurons.len().saturating_sub(neurons),
+            self.synapses.len().saturating_sub(synapses),
+        );
     }
 
+    /// Ajuste la chimie globale selon le ton du message utilisateur.
     pub fn update_modulator_from_feedback(&mut self, user_input: &str) {
-        println!("[Brain] Feedback utilisateur reçu: {}", user_input);
+        debug!(target: logging::MODULATOR, "Feedback utilisateur reçu: {}", user_input);
 
         if user_input.contains("bien") || user_input.contains("super") {
             self.modulator.dopamine += 0.1;
@@ -314,40 +548,6 @@ impl Brain {
         self.modulator.noradrenaline = self.modulator.noradrenaline.clamp(0.0, 1.0);
         self.modulator.endorphins = self.modulator.endorphins.clamp(0.0, 1.0);
 
-        println!("[Brain] Modulateur après mise à jour: {:?}", self.modulator);
-    }
-
-    pub fn generate_reply(&self, seed_words: &[Word]) -> String {
-        println!(
-            "[Brain] Génération de réponse à partir de seed_words: {:?}",
-            seed_words
-        );
-
-        let mut reply_words = HashSet::new();
-
-        for word in seed_words {
-            if let Some(neuron) = self.neurons.get(word)
-                && neuron.v > 0.5
-            {
-                for ((a, b), syn) in &self.synapses {
-                    if a == word && syn.strength > 0.1 {
-                        reply_words.insert(b.clone());
-                    } else if b == word && syn.strength > 0.1 {
-                        reply_words.insert(a.clone());
-                    }
-                }
-            }
-        }
-
-        let mut reply: Vec<String> = reply_words.into_iter().collect();
-        reply.sort();
-
-        let response = reply.join(" ");
-        println!("[Brain] Réponse générée: {}", response);
-        response
-    }
-
-    pub fn apply_feedback(&mut self, _feedback: &str, _last_reply_words: &[Word]) {
-        // Implémentation simplifiée
+        debug!(target: logging::MODULATOR, "Modulateur après mise à jour: {:?}", self.modulator);
     }
 }
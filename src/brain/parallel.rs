@@ -0,0 +1,133 @@
+// Tick dense multi-thread (feature `parallel`).
+//
+// Chaque neurone ne dépend que de l'état du début de tick : les potentiels
+// suivants sont calculés en parallèle dans un second tampon, puis recopiés.
+// Pour rester identique au bit près au tick séquentiel, les entrées d'une
+// cible sont additionnées dans le même ordre que `synaptic_inputs` : celui
+// des neurones actifs, dans l'ordre des mots.
+
+#[cfg(feature = "parallel")]
+use crate::brain::neurons::with_drift;
+#[cfg(feature = "parallel")]
+use crate::brain::orchestrator::tick_factors;
+use crate::brain::{Brain, NeuronId};
+#[cfg(feature = "parallel")]
+use crate::composition::Composition;
+#[cfg(feature = "parallel")]
+use crate::neuron::Neuron;
+#[cfg(feature = "parallel")]
+use crate::neuron_model::NeuronModel;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+impl Brain {
+    #[cfg(not(feature = "parallel"))]
+    pub(crate) fn tick_parallel(&mut self, t: usize) -> BTreeSet<NeuronId> {
+        self.tick_dense(t)
+    }
+
+    #[cfg(feature = "parallel")]
+    pub(crate) fn tick_parallel(&mut self, t: usize) -> BTreeSet<NeuronId> {
+        // Actifs = v > 0.9 en début de tick
+        let active: Vec<NeuronId> = self
+            .vocab
+            .iter()
+            .map(|(id, _)| id)
+            .filter(|id| self.neurons[id.index()].v > 0.9)
+            .collect();
+
+        // Contributions de chaque neurone actif, calculées en parallèle puis
+        // sommées dans l'ordre des mots, comme `synaptic_inputs`
+        let synapses = &self.synapses;
+        let contributions: Vec<Vec<(NeuronId, f32)>> = active
+            .par_iter()
+            .map(|&pre| {
+                synapses
+                    .outgoing(pre)
+                    .map(|(post, syn)| {
+                        let signed = if syn.is_excitatory() {
+                            syn.strength
+                        } else if syn.is_inhibitory() {
+                            -syn.strength
+                        } else {
+                            0.0
+                        };
+                        (post, signed)
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut inputs: BTreeMap<NeuronId, f32> = BTreeMap::new();
+        for (post, signed) in contributions.into_iter().flatten() {
+            *inputs.entry(post).or_default() += signed;
+        }
+
+        // Tampon arrière : état (v, u) après intégration de l'entrée
+        // synaptique, calculé sur une copie légère de chaque neurone
+        let default_model = self.neuron_model;
+        let drift = self.threshold_drift;
+        let next_state: Vec<(f32, f32)> = self
+            .neurons
+            .par_iter()
+            .enumerate()
+            .map(|(i, n)| {
+                if n.refractory > 0 {
+                    return (n.v, n.u);
+                }
+                let input_sum = inputs.get(&NeuronId(i as u32)).copied().unwrap_or(0.0);
+                let mut next = Neuron {
+                    composition: Composition::default(),
+                    ..*n
+                };
+                let model = n.model.unwrap_or(default_model);
+                with_drift(&mut next, drift, |next| {
+                    model.integrate(next, input_sum * n.gain)
+                });
+                (next.v, next.u)
+            })
+            .collect();
+
+        let (oscillation_factor, circadian_factor) = tick_factors(t);
+        let spiked: Vec<bool> = self
+            .neurons
+            .par_iter_mut()
+            .zip(next_state)
+            .map(|(n, (v, u))| {
+                let model = n.model.unwrap_or(default_model);
+                let mut spiked = false;
+                if n.refractory == 0 {
+                    n.v = v;
+                    n.u = u;
+                    if with_drift(n, drift, |n| model.fires(n)) {
+                        spiked = true;
+                        with_drift(n, drift, |n| model.reset(n));
+                        n.refractory = model.refractory();
+                        n.fired_count += 1;
+                    }
+                }
+                if n.refractory > 0 {
+                    n.refractory -= 1;
+                }
+
+                // Mêmes étapes, dans le même ordre, que le tick dense
+                if n.v > 1.5 {
+                    n.v = 1.5;
+                }
+                n.v += oscillation_factor * 0.05;
+                n.v *= circadian_factor;
+                spiked
+            })
+            .collect();
+        self.threshold_drift += oscillation_factor * 0.01;
+
+        spiked
+            .into_iter()
+            .enumerate()
+            .filter(|(_, spiked)| *spiked)
+            .map(|(i, _)| NeuronId(i as u32))
+            .collect()
+    }
+}
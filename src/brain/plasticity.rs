@@ -0,0 +1,77 @@
+// Plasticité dépendante du temps des spikes (STDP).
+// Un mot qui spike juste avant un autre renforce la synapse pré → post ;
+// l'arête inverse post → pré est affaiblie. C'est ce qui permet au bébé
+// d'apprendre des séquences, pas seulement des sacs de mots. Chaque paire
+// laisse aussi une trace d'éligibilité, pour la récompense différée.
+
+use crate::brain::{Brain, NeuronId, SynapseKey, synapses};
+use crate::neurotransmitter::Neurotransmitter;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Réglages de la STDP.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StdpParams {
+    pub a_plus: f32,   // Potentiation pré avant post
+    pub a_minus: f32,  // Dépression post avant pré
+    pub tau: f32,      // Constante de temps, en ticks
+    pub window: usize, // Écart maximal pris en compte, en ticks
+}
+
+impl Default for StdpParams {
+    fn default() -> Self {
+        Self {
+            a_plus: 0.05,
+            a_minus: 0.055, // Légèrement dominante : évite l'emballement
+            tau: 2.0,
+            window: 5,
+        }
+    }
+}
+
+impl Brain {
+    /// Applique la STDP sur toutes les paires (pré, post) séparées d'au plus
+    /// `window` ticks dans l'historique. La potentiation est modulée par la
+    /// dopamine, comme le reste de l'apprentissage excitateur.
+    pub(crate) fn apply_stdp(&mut self, spikes_history: &[BTreeSet<NeuronId>]) {
+        let params = self.stdp.clone();
+        let dop = self.modulator.dopamine.max(0.1);
+
+        for (t, posts) in spikes_history.iter().enumerate() {
+            let start = t.saturating_sub(params.window);
+            for (s, pres) in spikes_history.iter().enumerate().take(t).skip(start) {
+                let dt = (t - s) as f32;
+                let decay = (-dt / params.tau).exp();
+
+                for &pre in pres {
+                    for &post in posts {
+                        if pre == post {
+                            continue;
+                        }
+
+                        // Les synapses inhibitrices ont leur propre règle
+                        let syn = synapses::ensure_synapse(
+                            &mut self.synapses,
+                            pre,
+                            post,
+                            Neurotransmitter::Glutamate,
+                        );
+                        if syn.is_excitatory() {
+                            syn.strength += params.a_plus * decay * dop;
+                            syn.add_eligibility(decay);
+                        }
+
+                        let reverse = SynapseKey::new(post, pre);
+                        if let Some(rev) = self.synapses.get_mut(&reverse)
+                            && rev.is_excitatory()
+                        {
+                            rev.strength = (rev.strength - params.a_minus * decay).max(0.0);
+                            rev.add_eligibility(-decay);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
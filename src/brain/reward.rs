@@ -0,0 +1,182 @@
+// Apprentissage à trois facteurs : traces d'éligibilité et dopamine.
+//
+// Pré et post actifs ensemble ne changent pas seulement le poids : ils
+// laissent sur la synapse une trace d'éligibilité, qui s'efface en quelques
+// dizaines de ticks. Quand de la dopamine arrive — une approbation de
+// l'utilisateur, ou un neurone dopaminergique qui décharge — les traces
+// encore présentes deviennent des changements de poids. Le bébé apprend
+// ainsi d'un « bien ! » dit après sa réponse, pas seulement de ce qui
+// co-occurre.
+//
+// Les mots d'approbation projettent des synapses dopaminergiques vers les
+// mots qu'ils ont récompensés : à force, les entendre suffit à libérer de la
+// dopamine sur ces mots-là.
+
+use crate::brain::feedback::POSITIVE_WORDS;
+use crate::brain::{Brain, NeuronId, SynapseKey, neurons, synapses};
+use crate::composition::Composition;
+use crate::logging;
+use crate::neurotransmitter::Neurotransmitter;
+use crate::tokenizer::Word;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Réglages de l'apprentissage par récompense.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RewardParams {
+    pub enabled: bool,
+    pub trace_tau: f32,   // Durée de vie des traces, en ticks
+    pub lr: f32,          // Conversion trace × dopamine → poids
+    pub approval: f32,    // Dopamine libérée par une approbation franche
+    pub pathway_lr: f32,  // Croissance des synapses dopaminergiques
+    pub feedback_lr: f32, // Effet direct du feedback sur les synapses de la réponse
+}
+
+impl Default for RewardParams {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            trace_tau: 10.0,
+            lr: 0.5,
+            approval: 0.3,
+            pathway_lr: 0.05,
+            feedback_lr: 0.1,
+        }
+    }
+}
+
+impl Brain {
+    /// Efface les traces d'éligibilité au rythme de `ticks` ticks écoulés.
+    pub(crate) fn decay_eligibility(&mut self, ticks: usize) {
+        let factor = (-(ticks as f32) / self.reward.trace_tau.max(1e-3)).exp();
+        for syn in self.synapses.values_mut() {
+            syn.eligibility *= factor;
+        }
+    }
+
+    /// Rend éligibles les synapses qui ont produit la dernière réponse
+    /// ([`Brain::last_reply_synapses`]) : c'est à elles que reviendra la
+    /// récompense.
+    pub(crate) fn tag_reply(&mut self) {
+        for key in &self.last_reply_synapses {
+            if let Some(syn) = self.synapses.get_mut(key) {
+                syn.add_eligibility(1.0);
+            }
+        }
+    }
+
+    /// Libère `amount` de dopamine dans tout le cerveau : chaque synapse
+    /// éligible voit son poids changer de `lr × amount × trace`. Une quantité
+    /// négative (creux de dopamine) déprime ces mêmes synapses.
+    pub fn release_dopamine(&mut self, amount: f32) {
+        self.reinforce_eligible(|_| amount);
+    }
+
+    /// Récompense différée d'un feedback de score `score` (voir
+    /// [`crate::brain::feedback`]) : un pic ou un creux de dopamine convertit
+    /// les traces laissées par la réponse précédente. Les mots d'éloge de
+    /// `words` se relient aux mots de cette réponse, `reply_words`, par des
+    /// synapses dopaminergiques.
+    pub(crate) fn reward_reply(&mut self, score: f32, words: &[Word], reply_words: &[Word]) {
+        if !self.reward.enabled || score == 0.0 {
+            return;
+        }
+
+        info!(
+            target: logging::LEARNING,
+            "Dopamine {:+.2} sur {:?}",
+            score * self.reward.approval,
+            reply_words
+        );
+        self.release_dopamine(score * self.reward.approval);
+        if score < 0.0 {
+            return;
+        }
+
+        let approving: Vec<&Word> = words
+            .iter()
+            .filter(|w| POSITIVE_WORDS.contains(&w.as_str()))
+            .collect();
+
+        let targets: Vec<NeuronId> = reply_words
+            .iter()
+            .filter_map(|w| self.vocab.id(w))
+            .collect();
+        for word in approving {
+            let pre = neurons::ensure_neuron(
+                &mut self.vocab,
+                &mut self.neurons,
+                word,
+                Composition::default(),
+            );
+            for &post in &targets {
+                let key = SynapseKey::new(pre, post);
+                if pre == post
+                    || self
+                        .synapses
+                        .get(&key)
+                        .is_some_and(|syn| !syn.is_dopaminergic())
+                {
+                    continue;
+                }
+                let fresh = !self.synapses.contains_key(&key);
+                let syn = synapses::ensure_synapse(
+                    &mut self.synapses,
+                    pre,
+                    post,
+                    Neurotransmitter::Dopamine,
+                );
+                if fresh {
+                    syn.strength = 0.0;
+                }
+                // Jamais plus que l'approbation explicite
+                syn.strength = (syn.strength + self.reward.pathway_lr).min(self.reward.approval);
+            }
+        }
+    }
+
+    /// Les neurones dopaminergiques qui ont déchargé libèrent de la dopamine
+    /// sur leurs cibles.
+    pub(crate) fn release_from_dopaminergic(&mut self, spikes_history: &[BTreeSet<NeuronId>]) {
+        if !self.reward.enabled {
+            return;
+        }
+        let fired: BTreeSet<NeuronId> = spikes_history.iter().flatten().copied().collect();
+        let mut local: BTreeMap<NeuronId, f32> = BTreeMap::new();
+        for &pre in &fired {
+            for (post, syn) in self.synapses.outgoing(pre) {
+                if syn.is_dopaminergic() {
+                    *local.entry(post).or_default() += syn.strength;
+                }
+            }
+        }
+        if !local.is_empty() {
+            self.reinforce_eligible(|post| local.get(&post).copied().unwrap_or(0.0));
+        }
+    }
+
+    // Troisième facteur : dopamine reçue par le neurone post-synaptique
+    fn reinforce_eligible(&mut self, dopamine_at: impl Fn(NeuronId) -> f32) {
+        if !self.reward.enabled {
+            return;
+        }
+        let lr = self.reward.lr;
+        let mut changed = 0;
+        for (key, syn) in self.synapses.iter_mut() {
+            let dopamine = dopamine_at(key.post);
+            if !syn.is_excitatory() || syn.eligibility == 0.0 || dopamine == 0.0 {
+                continue;
+            }
+            syn.strength =
+                (syn.strength + lr * dopamine * syn.eligibility).clamp(0.0, self.exc_max);
+            changed += 1;
+        }
+        debug!(
+            target: logging::LEARNING,
+            "Dopamine : {} synapses éligibles modifiées",
+            changed
+        );
+    }
+}
@@ -1,25 +1,143 @@
+use crate::brain::NeuronId;
 use crate::neurotransmitter::Neurotransmitter;
 use crate::synapse::Synapse;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, btree_map};
+use std::ops::{Deref, RangeInclusive};
 
-pub type Word = String;
+/// Clé d'une synapse orientée pré → post. C'est la seule façon de désigner
+/// une synapse : spiking, apprentissage, réponse et sauvegarde passent tous
+/// par [`SynapseKey::new`], les recherches ne peuvent donc pas diverger.
+///
+/// L'ordre des clés est celui de (pré, post) : les synapses sortantes d'un
+/// neurone sont contiguës.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct SynapseKey {
+    pub pre: NeuronId,
+    pub post: NeuronId,
+}
+
+impl SynapseKey {
+    pub fn new(pre: NeuronId, post: NeuronId) -> Self {
+        Self { pre, post }
+    }
+
+    // Toutes les clés dont `pre` est l'origine
+    fn outgoing_range(pre: NeuronId) -> RangeInclusive<SynapseKey> {
+        Self::new(pre, NeuronId(0))..=Self::new(pre, NeuronId(u32::MAX))
+    }
+}
+
+/// Les synapses du cerveau. Les synapses sortantes d'un neurone se lisent
+/// directement dans la map triée ; un index des entrées, tenu à jour à
+/// chaque création/suppression, donne les prédécesseurs sans parcourir
+/// toutes les synapses.
+///
+/// En lecture, se manipule comme la `BTreeMap<SynapseKey, Synapse>` qu'elle
+/// enveloppe ; les modifications passent par ses méthodes pour que l'index
+/// reste cohérent.
+#[derive(Clone, Debug, Default)]
+pub struct SynapseStore {
+    map: BTreeMap<SynapseKey, Synapse>,
+    incoming: Vec<BTreeSet<NeuronId>>,
+}
+
+impl SynapseStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Neurones vers lesquels `pre` projette.
+    pub fn successors(&self, pre: NeuronId) -> impl Iterator<Item = NeuronId> + '_ {
+        self.outgoing(pre).map(|(post, _)| post)
+    }
+
+    /// Neurones qui projettent vers `post`.
+    pub fn predecessors(&self, post: NeuronId) -> impl Iterator<Item = NeuronId> + '_ {
+        self.incoming
+            .get(post.index())
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Synapses sortantes de `pre`, avec leur cible.
+    pub fn outgoing(&self, pre: NeuronId) -> impl Iterator<Item = (NeuronId, &Synapse)> + '_ {
+        self.map
+            .range(SynapseKey::outgoing_range(pre))
+            .map(|(key, syn)| (key.post, syn))
+    }
+
+    pub fn get_mut(&mut self, key: &SynapseKey) -> Option<&mut Synapse> {
+        self.map.get_mut(key)
+    }
 
-fn syn_key(a: &str, b: &str) -> (Word, Word) {
-    if a <= b {
-        (a.to_string(), b.to_string())
-    } else {
-        (b.to_string(), a.to_string())
+    pub fn iter_mut(&mut self) -> btree_map::IterMut<'_, SynapseKey, Synapse> {
+        self.map.iter_mut()
+    }
+
+    pub fn values_mut(&mut self) -> btree_map::ValuesMut<'_, SynapseKey, Synapse> {
+        self.map.values_mut()
+    }
+
+    pub fn insert(&mut self, key: SynapseKey, synapse: Synapse) -> Option<Synapse> {
+        self.index(&key);
+        self.map.insert(key, synapse)
+    }
+
+    pub fn remove(&mut self, key: &SynapseKey) -> Option<Synapse> {
+        let removed = self.map.remove(key)?;
+        if let Some(pres) = self.incoming.get_mut(key.post.index()) {
+            pres.remove(&key.pre);
+        }
+        Some(removed)
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.incoming.clear();
+    }
+
+    fn index(&mut self, key: &SynapseKey) {
+        let post = key.post.index();
+        if self.incoming.len() <= post {
+            self.incoming.resize_with(post + 1, BTreeSet::new);
+        }
+        self.incoming[post].insert(key.pre);
+    }
+}
+
+impl Deref for SynapseStore {
+    type Target = BTreeMap<SynapseKey, Synapse>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
     }
 }
 
+impl FromIterator<(SynapseKey, Synapse)> for SynapseStore {
+    fn from_iter<I: IntoIterator<Item = (SynapseKey, Synapse)>>(iter: I) -> Self {
+        let mut store = Self::new();
+        for (key, synapse) in iter {
+            store.insert(key, synapse);
+        }
+        store
+    }
+}
+
+/// Crée la synapse pré → post si besoin et la renvoie.
 pub fn ensure_synapse(
-    synapses: &mut HashMap<(Word, Word), Synapse>,
-    a: &str,
-    b: &str,
+    synapses: &mut SynapseStore,
+    pre: NeuronId,
+    post: NeuronId,
     neurotransmitter: Neurotransmitter,
-) {
-    let key = syn_key(a, b);
+) -> &mut Synapse {
+    let key = SynapseKey::new(pre, post);
+    if !synapses.map.contains_key(&key) {
+        synapses.index(&key);
+    }
     synapses
+        .map
         .entry(key)
-        .or_insert_with(|| Synapse::new(neurotransmitter, 0.5)); // Ajout d'une force par défaut
+        .or_insert_with(|| Synapse::new(neurotransmitter, 0.5)) // Ajout d'une force par défaut
 }
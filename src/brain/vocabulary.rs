@@ -0,0 +1,83 @@
+// Vocabulaire : chaque mot est interné une fois pour toutes en un
+// identifiant dense. Le cœur de la simulation ne manipule que des
+// identifiants ; les chaînes n'apparaissent qu'aux bords (entrée du
+// tokenizer, réponse, sauvegarde).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub type Word = String;
+
+/// Identifiant d'un neurone-mot : son indice dans `Brain::neurons`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct NeuronId(pub u32);
+
+impl NeuronId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Table mots ↔ identifiants. Les identifiants sont attribués dans l'ordre
+/// d'apparition des mots et ne changent jamais.
+#[derive(Clone, Debug, Default)]
+pub struct Vocabulary {
+    words: Vec<Word>,
+    ids: HashMap<Word, NeuronId>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Identifiant de `w`, attribué au premier appel.
+    pub fn intern(&mut self, w: &str) -> NeuronId {
+        if let Some(&id) = self.ids.get(w) {
+            return id;
+        }
+        let id = NeuronId(self.words.len() as u32);
+        self.words.push(w.to_string());
+        self.ids.insert(w.to_string(), id);
+        id
+    }
+
+    /// Identifiant de `w`, s'il est déjà connu.
+    pub fn id(&self, w: &str) -> Option<NeuronId> {
+        self.ids.get(w).copied()
+    }
+
+    /// Le mot derrière `id`. Panique si l'identifiant n'a pas été attribué
+    /// par ce vocabulaire.
+    pub fn word(&self, id: NeuronId) -> &str {
+        &self.words[id.index()]
+    }
+
+    /// Les mots derrière une suite d'identifiants (pour les traces).
+    pub fn resolve<I>(&self, ids: I) -> Vec<&str>
+    where
+        I: IntoIterator<Item = NeuronId>,
+    {
+        ids.into_iter().map(|id| self.word(id)).collect()
+    }
+
+    /// Tous les mots, dans l'ordre des identifiants.
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NeuronId, &Word)> {
+        self.words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (NeuronId(i as u32), w))
+    }
+}
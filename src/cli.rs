@@ -0,0 +1,287 @@
+// Lecture (volontairement minimaliste) de la ligne de commande du REPL.
+
+use ai_baby::brain::{MetricsFormat, MetricsRecorder};
+use ai_baby::export::GraphFilter;
+use ai_baby::logging::{self, LogFilter, Logger};
+use ai_baby::persist::journal::seed_path;
+use ai_baby::persist::{Autosave, DEFAULT_BACKUPS, Format};
+use ai_baby::{Brain, NeuronModelKind, SimulationMode, TokenizerKind};
+use log::{LevelFilter, error};
+
+const USAGE: &str = "\
+usage:
+  ai_baby [--brain CHEMIN] [--format json|binary] [--backups N] [--seed N]
+          [--sim dense|event|parallel] [--model lif|rs|ib|ch|adex]
+          [--tokenizer word|syllable|ngram|ngramN] [--fold-accents]
+          [--journal CHEMIN | --no-journal]
+          [-v | --verbose] [--log-file CHEMIN] [--metrics CHEMIN.csv|CHEMIN.jsonl]
+          [--tui]
+  ai_baby convert SOURCE DESTINATION [--format json|binary]
+  ai_baby replay [SNAPSHOT] JOURNAL [--until TOUR] [--out CHEMIN]
+  ai_baby graph DESTINATION.dot|.graphml|.gexf [--top K] [--ego MOT] [--radius N]
+
+RUST_LOG règle finement les messages (ex. `info,brain.spiking=debug`).";
+
+pub enum Command {
+    Repl,
+    Convert {
+        src: String,
+        dst: String,
+    },
+    Replay {
+        seed: String,
+        journal: String,
+        until: Option<u64>,
+        out: Option<String>,
+    },
+    /// Exporte le réseau du cerveau (`--brain`) pour Graphviz ou Gephi.
+    Graph {
+        dst: String,
+        filter: GraphFilter,
+    },
+}
+
+pub struct Options {
+    pub command: Command,
+    pub brain_path: String,
+    /// Format imposé ; sinon déduit de l'extension du fichier.
+    pub format: Option<Format>,
+    /// Nombre de sauvegardes tournantes gardées à côté du cerveau.
+    pub backups: usize,
+    /// Journal d'expérience ; `None` si désactivé.
+    pub journal_path: Option<String>,
+    /// Graine des cerveaux neufs (sinon aléatoire).
+    pub seed: Option<u64>,
+    /// Mode de simulation imposé au cerveau chargé.
+    pub simulation: Option<SimulationMode>,
+    /// Modèle de neurone imposé au cerveau chargé.
+    pub neuron_model: Option<NeuronModelKind>,
+    /// Découpage du texte imposé au cerveau chargé.
+    pub tokenizer: Option<TokenizerKind>,
+    /// Accents retirés avant découpage (« bébé » = « bebe »).
+    pub fold_accents: bool,
+    /// Messages du cerveau à afficher ; `RUST_LOG` l'emporte sur `--verbose`.
+    pub log_filter: LogFilter,
+    /// Copie JSON-lines des messages affichés.
+    pub log_file: Option<String>,
+    /// Télémétrie tick par tick (CSV si `.csv`, JSON-lines sinon).
+    pub metrics_path: Option<String>,
+    /// Tableau de bord plein écran au lieu du REPL ligne à ligne.
+    pub tui: bool,
+}
+
+impl Options {
+    pub fn parse<I: Iterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut brain_path = "baby_brain.json".to_string();
+        let mut format = None;
+        let mut backups = DEFAULT_BACKUPS;
+        let mut journal_path = None;
+        let mut no_journal = false;
+        let mut until = None;
+        let mut out = None;
+        let mut seed = None;
+        let mut simulation = None;
+        let mut neuron_model = None;
+        let mut tokenizer = None;
+        let mut fold_accents = false;
+        let mut verbose = false;
+        let mut log_file = None;
+        let mut metrics_path = None;
+        let mut top_k = None;
+        let mut ego = None;
+        let mut radius = 1;
+        let mut tui = false;
+        let mut positional = vec![];
+
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--brain" => brain_path = value(&mut args, "--brain")?,
+                "--format" => format = Some(value(&mut args, "--format")?.parse()?),
+                "--backups" => {
+                    backups = value(&mut args, "--backups")?
+                        .parse()
+                        .map_err(|_| "--backups attend un entier".to_string())?;
+                }
+                "--journal" => journal_path = Some(value(&mut args, "--journal")?),
+                "--no-journal" => no_journal = true,
+                "--until" => {
+                    until = Some(
+                        value(&mut args, "--until")?
+                            .parse()
+                            .map_err(|_| "--until attend un numéro de tour".to_string())?,
+                    );
+                }
+                "--seed" => {
+                    seed = Some(
+                        value(&mut args, "--seed")?
+                            .parse()
+                            .map_err(|_| "--seed attend un entier".to_string())?,
+                    );
+                }
+                "--sim" => {
+                    simulation = Some(match value(&mut args, "--sim")?.as_str() {
+                        "dense" => SimulationMode::Dense,
+                        "event" => SimulationMode::EventDriven,
+                        "parallel" => SimulationMode::Parallel,
+                        other => return Err(format!("mode de simulation inconnu: {}", other)),
+                    });
+                }
+                "--model" => neuron_model = Some(value(&mut args, "--model")?.parse()?),
+                "--tokenizer" => tokenizer = Some(value(&mut args, "--tokenizer")?.parse()?),
+                "--fold-accents" => fold_accents = true,
+                "-v" | "--verbose" => verbose = true,
+                "--log-file" => log_file = Some(value(&mut args, "--log-file")?),
+                "--metrics" => metrics_path = Some(value(&mut args, "--metrics")?),
+                "--top" => {
+                    top_k = Some(
+                        value(&mut args, "--top")?
+                            .parse()
+                            .map_err(|_| "--top attend un entier".to_string())?,
+                    );
+                }
+                "--ego" => ego = Some(value(&mut args, "--ego")?),
+                "--radius" => {
+                    radius = value(&mut args, "--radius")?
+                        .parse()
+                        .map_err(|_| "--radius attend un entier".to_string())?;
+                }
+                "--tui" if cfg!(feature = "tui") => tui = true,
+                "--tui" => {
+                    return Err(
+                        "--tui demande la fonctionnalité `tui` (cargo run --features tui)"
+                            .to_string(),
+                    );
+                }
+                "--out" => out = Some(value(&mut args, "--out")?),
+                "-h" | "--help" => return Err(USAGE.to_string()),
+                _ if arg.starts_with("--") => {
+                    return Err(format!("option inconnue: {}\n{}", arg, USAGE));
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let command = match positional.as_slice() {
+            [] => Command::Repl,
+            [cmd, src, dst] if cmd == "convert" => Command::Convert {
+                src: src.clone(),
+                dst: dst.clone(),
+            },
+            [cmd, seed, journal] if cmd == "replay" => Command::Replay {
+                seed: seed.clone(),
+                journal: journal.clone(),
+                until,
+                out,
+            },
+            // Sans snapshot, celui que la session a écrit à côté du journal
+            [cmd, journal] if cmd == "replay" => Command::Replay {
+                seed: seed_path(journal).display().to_string(),
+                journal: journal.clone(),
+                until,
+                out,
+            },
+            [cmd, dst] if cmd == "graph" => Command::Graph {
+                dst: dst.clone(),
+                filter: GraphFilter {
+                    top_k,
+                    ego: ego.map(|word| (word, radius)),
+                },
+            },
+            _ => return Err(USAGE.to_string()),
+        };
+
+        let journal_path = if no_journal {
+            None
+        } else {
+            Some(journal_path.unwrap_or_else(|| format!("{}.journal", brain_path)))
+        };
+
+        let log_filter = match std::env::var("RUST_LOG") {
+            Ok(spec) if !spec.trim().is_empty() => spec.parse()?,
+            _ if verbose => LogFilter::new(LevelFilter::Debug),
+            _ => LogFilter::default(),
+        };
+
+        Ok(Self {
+            command,
+            brain_path,
+            format,
+            backups,
+            journal_path,
+            seed,
+            simulation,
+            neuron_model,
+            tokenizer,
+            fold_accents,
+            log_filter,
+            log_file,
+            metrics_path,
+            tui,
+        })
+    }
+
+    /// Installe le logger choisi sur la ligne de commande.
+    pub fn init_logging(&self) -> Result<(), String> {
+        let mut logger = Logger::new(self.log_filter.clone());
+        if self.tui {
+            logger = logger.without_console();
+        }
+        if let Some(path) = &self.log_file {
+            logger = logger
+                .with_file(path)
+                .map_err(|e| format!("fichier de log {} inaccessible: {}", path, e))?;
+        }
+        logger.install();
+        Ok(())
+    }
+
+    /// Un cerveau neuf, reproductible si `--seed` est donné.
+    pub fn new_brain(&self) -> Brain {
+        let mut brain = self.seed.map(Brain::with_seed).unwrap_or_default();
+        self.configure(&mut brain);
+        brain
+    }
+
+    /// Applique au cerveau les réglages de la ligne de commande.
+    pub fn configure(&self, brain: &mut Brain) {
+        if let Some(simulation) = self.simulation {
+            brain.simulation = simulation;
+        }
+        if let Some(neuron_model) = self.neuron_model {
+            brain.neuron_model = neuron_model;
+        }
+        if let Some(tokenizer) = self.tokenizer {
+            brain.tokenizer = tokenizer;
+        }
+        if self.fold_accents {
+            brain.normalization.fold_accents = true;
+        }
+        if let Some(path) = &self.metrics_path {
+            match MetricsRecorder::to_file(path, MetricsFormat::from_path(path)) {
+                Ok(recorder) => brain.metrics.recorder = Some(recorder),
+                Err(e) => error!(
+                    target: logging::PERSIST,
+                    "Télémétrie {} inaccessible: {}", path, e
+                ),
+            }
+        }
+    }
+
+    /// Emplacement de sauvegarde du cerveau du REPL.
+    pub fn autosave(&self) -> Autosave {
+        Autosave::new(&self.brain_path)
+            .with_format(self.format_for(&self.brain_path))
+            .with_backups(self.backups)
+    }
+
+    /// Format à utiliser pour écrire dans `path`.
+    pub fn format_for(&self, path: &str) -> Format {
+        self.format.unwrap_or_else(|| Format::from_path(path))
+    }
+}
+
+fn value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("{} attend une valeur\n{}", flag, USAGE))
+}
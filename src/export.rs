@@ -0,0 +1,346 @@
+// Export du réseau pour le regarder dans Graphviz ou Gephi.
+//
+// Les neurones-mots deviennent des nœuds, les synapses des arêtes orientées
+// de `pre` vers `post`. On peut ne garder que les arêtes les plus fortes, ou
+// le voisinage d'un mot : un cerveau qui a beaucoup parlé devient vite
+// illisible en entier.
+
+use crate::brain::{Brain, NeuronId, SynapseKey};
+use crate::neuron::Neuron;
+use crate::synapse::Synapse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Format du fichier de graphe.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz (`dot -Tsvg`).
+    #[default]
+    Dot,
+    GraphMl,
+    /// Format natif de Gephi.
+    Gexf,
+}
+
+impl GraphFormat {
+    /// Devine le format d'après l'extension : `.graphml`, `.gexf`, DOT sinon.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some("graphml") => GraphFormat::GraphMl,
+            Some("gexf") => GraphFormat::Gexf,
+            _ => GraphFormat::Dot,
+        }
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dot" | "gv" => Ok(GraphFormat::Dot),
+            "graphml" => Ok(GraphFormat::GraphMl),
+            "gexf" => Ok(GraphFormat::Gexf),
+            other => Err(format!("format de graphe inconnu: {}", other)),
+        }
+    }
+}
+
+/// Partie du réseau à exporter. Par défaut, tout.
+#[derive(Clone, Debug, Default)]
+pub struct GraphFilter {
+    /// Seulement les `k` synapses les plus fortes.
+    pub top_k: Option<usize>,
+    /// Seulement les mots à au plus `radius` synapses de ce mot, dans un
+    /// sens ou dans l'autre. Un mot inconnu donne un graphe vide.
+    pub ego: Option<(String, usize)>,
+}
+
+impl GraphFilter {
+    pub fn with_top_k(mut self, k: usize) -> Self {
+        self.top_k = Some(k);
+        self
+    }
+
+    pub fn with_ego(mut self, word: &str, radius: usize) -> Self {
+        self.ego = Some((word.to_string(), radius));
+        self
+    }
+}
+
+/// Nœuds et arêtes retenus par un [`GraphFilter`].
+struct Graph<'a> {
+    nodes: BTreeMap<NeuronId, (&'a str, &'a Neuron)>,
+    edges: Vec<(SynapseKey, &'a Synapse, Direction)>,
+    drift: f32, // Dérive commune des seuils (voir `Brain::threshold_drift`)
+}
+
+/// Sens d'une arête : seule, ou doublée d'une synapse en retour.
+#[derive(Clone, Copy)]
+enum Direction {
+    Unidirectional,
+    Bidirectional,
+}
+
+impl Direction {
+    fn of(brain: &Brain, key: SynapseKey) -> Self {
+        if brain
+            .synapses
+            .contains_key(&SynapseKey::new(key.post, key.pre))
+        {
+            Direction::Bidirectional
+        } else {
+            Direction::Unidirectional
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Direction::Unidirectional => "unidirectional",
+            Direction::Bidirectional => "bidirectional",
+        }
+    }
+}
+
+impl<'a> Graph<'a> {
+    fn new(brain: &'a Brain, filter: &GraphFilter) -> Self {
+        let mut ids: BTreeSet<NeuronId> = match &filter.ego {
+            None => brain.vocab.iter().map(|(id, _)| id).collect(),
+            Some((word, radius)) => match brain.vocab.id(word) {
+                Some(center) => neighbourhood(brain, center, *radius),
+                None => BTreeSet::new(),
+            },
+        };
+
+        let mut edges: Vec<(SynapseKey, &Synapse, Direction)> = brain
+            .synapses
+            .iter()
+            .filter(|(key, _)| ids.contains(&key.pre) && ids.contains(&key.post))
+            .map(|(&key, syn)| (key, syn, Direction::of(brain, key)))
+            .collect();
+
+        if let Some(k) = filter.top_k {
+            // Tri stable : à force égale, l'ordre des identifiants départage
+            edges.sort_by(|a, b| b.1.strength.total_cmp(&a.1.strength));
+            edges.truncate(k);
+            edges.sort_by_key(|&(key, _, _)| key);
+            let center = filter.ego.as_ref().and_then(|(w, _)| brain.vocab.id(w));
+            ids = edges
+                .iter()
+                .flat_map(|(key, _, _)| [key.pre, key.post])
+                .chain(center)
+                .collect();
+        }
+
+        let nodes = ids
+            .into_iter()
+            .map(|id| (id, (brain.vocab.word(id), &brain.neurons[id.index()])))
+            .collect();
+        Self {
+            nodes,
+            edges,
+            drift: brain.threshold_drift,
+        }
+    }
+}
+
+// Mots atteints en au plus `radius` synapses, sans tenir compte du sens
+fn neighbourhood(brain: &Brain, center: NeuronId, radius: usize) -> BTreeSet<NeuronId> {
+    let mut seen = BTreeSet::from([center]);
+    let mut frontier = vec![center];
+    for _ in 0..radius {
+        let mut next = vec![];
+        for id in frontier {
+            for other in brain
+                .synapses
+                .successors(id)
+                .chain(brain.synapses.predecessors(id))
+            {
+                if seen.insert(other) {
+                    next.push(other);
+                }
+            }
+        }
+        frontier = next;
+    }
+    seen
+}
+
+// Molécules sous la forme `nom:concentration;…`
+fn composition(neuron: &Neuron) -> String {
+    neuron
+        .composition
+        .iter()
+        .map(|m| format!("{}:{}", m.name, m.concentration))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Le graphe du cerveau (filtré) dans le format demandé.
+pub fn export_graph(brain: &Brain, filter: &GraphFilter, format: GraphFormat) -> String {
+    let graph = Graph::new(brain, filter);
+    match format {
+        GraphFormat::Dot => to_dot(brain, &graph),
+        GraphFormat::GraphMl => to_graphml(&graph),
+        GraphFormat::Gexf => to_gexf(&graph),
+    }
+}
+
+/// Écrit le graphe du cerveau dans `path`, au format deviné d'après
+/// l'extension.
+pub fn write_graph<P: AsRef<Path>>(brain: &Brain, path: P, filter: &GraphFilter) -> io::Result<()> {
+    let format = GraphFormat::from_path(&path);
+    fs::write(path, export_graph(brain, filter, format))
+}
+
+fn to_dot(brain: &Brain, graph: &Graph) -> String {
+    let mut out = String::from("digraph brain {\n");
+    for (id, (word, n)) in &graph.nodes {
+        let _ = writeln!(
+            out,
+            "  n{} [label=\"{}\", threshold={}, fired_count={}, composition=\"{}\"];",
+            id.index(),
+            escape_dot(word),
+            n.threshold + graph.drift,
+            n.fired_count,
+            escape_dot(&composition(n))
+        );
+    }
+    for (key, syn, direction) in &graph.edges {
+        // Épaisseur selon la force, rouge pour l'inhibition
+        let color = if syn.is_inhibitory() {
+            "red"
+        } else if syn.is_dopaminergic() {
+            "blue"
+        } else {
+            "black"
+        };
+        let penwidth = 0.5 + 3.0 * syn.strength / brain.exc_max.max(f32::EPSILON);
+        let _ = writeln!(
+            out,
+            "  n{} -> n{} [strength={}, neurotransmitter=\"{}\", direction={}, color={}, penwidth={:.2}];",
+            key.pre.index(),
+            key.post.index(),
+            syn.strength,
+            syn.neurotransmitter,
+            direction.as_str(),
+            color,
+            penwidth
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+const GRAPHML_HEADER: &[&str] = &[
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+    r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#,
+    r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#,
+    r#"  <key id="threshold" for="node" attr.name="threshold" attr.type="double"/>"#,
+    r#"  <key id="fired_count" for="node" attr.name="fired_count" attr.type="int"/>"#,
+    r#"  <key id="composition" for="node" attr.name="composition" attr.type="string"/>"#,
+    r#"  <key id="strength" for="edge" attr.name="strength" attr.type="double"/>"#,
+    r#"  <key id="neurotransmitter" for="edge" attr.name="neurotransmitter" attr.type="string"/>"#,
+    r#"  <key id="direction" for="edge" attr.name="direction" attr.type="string"/>"#,
+    r#"  <graph id="brain" edgedefault="directed">"#,
+];
+
+fn to_graphml(graph: &Graph) -> String {
+    let mut out = GRAPHML_HEADER.join("\n") + "\n";
+    for (id, (word, n)) in &graph.nodes {
+        let _ = writeln!(
+            out,
+            "    <node id=\"n{}\"><data key=\"label\">{}</data>\
+<data key=\"threshold\">{}</data><data key=\"fired_count\">{}</data>\
+<data key=\"composition\">{}</data></node>",
+            id.index(),
+            escape_xml(word),
+            n.threshold + graph.drift,
+            n.fired_count,
+            escape_xml(&composition(n))
+        );
+    }
+    for (i, (key, syn, direction)) in graph.edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\">\
+<data key=\"strength\">{}</data><data key=\"neurotransmitter\">{}</data>\
+<data key=\"direction\">{}</data></edge>",
+            i,
+            key.pre.index(),
+            key.post.index(),
+            syn.strength,
+            syn.neurotransmitter,
+            direction.as_str()
+        );
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+const GEXF_HEADER: &[&str] = &[
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+    r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#,
+    r#"  <graph mode="static" defaultedgetype="directed">"#,
+    r#"    <attributes class="node">"#,
+    r#"      <attribute id="threshold" title="threshold" type="double"/>"#,
+    r#"      <attribute id="fired_count" title="fired_count" type="integer"/>"#,
+    r#"      <attribute id="composition" title="composition" type="string"/>"#,
+    r#"    </attributes>"#,
+    r#"    <attributes class="edge">"#,
+    r#"      <attribute id="neurotransmitter" title="neurotransmitter" type="string"/>"#,
+    r#"      <attribute id="direction" title="direction" type="string"/>"#,
+    r#"    </attributes>"#,
+    r#"    <nodes>"#,
+];
+
+fn to_gexf(graph: &Graph) -> String {
+    let mut out = GEXF_HEADER.join("\n") + "\n";
+    for (id, (word, n)) in &graph.nodes {
+        let _ = writeln!(
+            out,
+            "      <node id=\"n{}\" label=\"{}\"><attvalues>\
+<attvalue for=\"threshold\" value=\"{}\"/><attvalue for=\"fired_count\" value=\"{}\"/>\
+<attvalue for=\"composition\" value=\"{}\"/></attvalues></node>",
+            id.index(),
+            escape_xml(word),
+            n.threshold + graph.drift,
+            n.fired_count,
+            escape_xml(&composition(n))
+        );
+    }
+    out.push_str("    </nodes>\n    <edges>\n");
+    // Le poids Gephi est la force de la synapse
+    for (i, (key, syn, direction)) in graph.edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "      <edge id=\"e{}\" source=\"n{}\" target=\"n{}\" weight=\"{}\"><attvalues>\
+<attvalue for=\"neurotransmitter\" value=\"{}\"/>\
+<attvalue for=\"direction\" value=\"{}\"/></attvalues></edge>",
+            i,
+            key.pre.index(),
+            key.post.index(),
+            syn.strength,
+            syn.neurotransmitter,
+            direction.as_str()
+        );
+    }
+    out.push_str("    </edges>\n  </graph>\n</gexf>\n");
+    out
+}
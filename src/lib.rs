@@ -0,0 +1,46 @@
+//! Compagnon "bébé neuronal" spiking + chimie jouet, en bibliothèque.
+//!
+//! Le point d'entrée est [`Brain`] : on le crée, on lui parle avec
+//! [`Brain::interact`] (ou on pilote soi-même [`Brain::run_spiking`],
+//! [`Brain::learn_from_spikes`] et [`Brain::generate_reply`]), puis on le
+//! sauvegarde avec [`persist::save`] / [`persist::load`].
+//!
+//! ```no_run
+//! use ai_baby::{Brain, persist};
+//!
+//! let mut brain = match persist::load("baby_brain.json") {
+//!     Ok(brain) => brain,
+//!     Err(e) if e.is_not_found() => Brain::new(),
+//!     Err(e) => panic!("cerveau illisible: {e}"),
+//! };
+//! let reply = brain.interact("bonjour maman");
+//! println!("{reply}");
+//! persist::save(&brain, "baby_brain.json")?;
+//! # Ok::<(), ai_baby::persist::PersistError>(())
+//! ```
+
+pub mod brain;
+pub mod composition;
+pub mod export;
+pub mod logging;
+pub mod modulator;
+pub mod neuron;
+pub mod neuron_model;
+pub mod neurotransmitter;
+pub mod normalize;
+pub mod persist;
+pub mod synapse;
+pub mod tokenizer;
+
+pub use brain::{Brain, NeuronId, SimulationMode, SynapseKey, Vocabulary};
+pub use composition::{Composition, Molecule};
+pub use modulator::Modulator;
+pub use neuron::Neuron;
+pub use neuron_model::{NeuronModel, NeuronModelKind};
+pub use neurotransmitter::Neurotransmitter;
+pub use normalize::Normalization;
+pub use synapse::Synapse;
+pub use tokenizer::{Tokenizer, TokenizerKind, tokenize};
+
+/// Un mot : c'est aussi l'identifiant d'un neurone.
+pub type Word = String;
@@ -0,0 +1,186 @@
+// Journal de bord du cerveau, par niveaux et par cibles.
+//
+// La bibliothèque écrit via la façade `log` sous quelques cibles
+// (`brain.spiking`, `brain.learning`, …) ; le REPL installe [`Logger`], qui
+// filtre comme `RUST_LOG` (`warn,brain.spiking=debug`), écrit sur la sortie
+// d'erreur et, au besoin, en JSON-lines dans un fichier pour l'analyse.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Propagation des spikes, tick par tick.
+pub const SPIKING: &str = "brain.spiking";
+/// Plasticité : STDP, récompense, feedback, inhibition.
+pub const LEARNING: &str = "brain.learning";
+/// Génération des réponses et babillage.
+pub const REPLY: &str = "brain.reply";
+/// Chimie globale.
+pub const MODULATOR: &str = "modulator";
+/// Sauvegardes, journal d'expérience.
+pub const PERSIST: &str = "persist";
+
+/// Filtre à la `RUST_LOG` : un niveau par défaut et des niveaux par cible.
+/// Une cible couvre ses sous-cibles (`brain` couvre `brain.spiking`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogFilter {
+    pub default: LevelFilter,
+    pub targets: Vec<(String, LevelFilter)>,
+}
+
+impl Default for LogFilter {
+    /// Silencieux : seuls les avertissements et les erreurs passent.
+    fn default() -> Self {
+        Self::new(LevelFilter::Warn)
+    }
+}
+
+impl LogFilter {
+    pub fn new(default: LevelFilter) -> Self {
+        Self {
+            default,
+            targets: vec![],
+        }
+    }
+
+    /// Niveau retenu pour `target` : celui de la cible la plus précise.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| {
+                target == prefix
+                    || target
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    /// Niveau le plus bavard du filtre, pour [`log::set_max_level`].
+    pub fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+/// `warn`, `brain.learning=debug` ou une liste séparée par des virgules.
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_level = |level: &str| {
+            level
+                .trim()
+                .parse::<LevelFilter>()
+                .map_err(|_| format!("niveau de log inconnu: {}", level))
+        };
+        let mut filter = Self::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => filter
+                    .targets
+                    .push((target.trim().to_string(), parse_level(level)?)),
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+// Une ligne du fichier JSON-lines
+#[derive(Serialize)]
+struct Entry<'a> {
+    ts_ms: u64,
+    level: &'a str,
+    target: &'a str,
+    message: String,
+}
+
+/// Écrit les messages retenus sur la sortie d'erreur, et en JSON-lines dans
+/// un fichier si on lui en donne un.
+pub struct Logger {
+    filter: LogFilter,
+    console: bool,
+    file: Option<Mutex<LineWriter<File>>>,
+}
+
+impl Logger {
+    pub fn new(filter: LogFilter) -> Self {
+        Self {
+            filter,
+            console: true,
+            file: None,
+        }
+    }
+
+    /// N'écrit plus sur la sortie d'erreur (seulement dans le fichier),
+    /// pour ne pas barbouiller un affichage plein écran.
+    pub fn without_console(mut self) -> Self {
+        self.console = false;
+        self
+    }
+
+    /// Ajoute (en fin de fichier) chaque message retenu dans `path`.
+    pub fn with_file(mut self, path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        self.file = Some(Mutex::new(LineWriter::new(file)));
+        Ok(self)
+    }
+
+    /// Installe ce logger pour tout le processus. Sans effet si un logger
+    /// est déjà en place.
+    pub fn install(self) {
+        let max_level = self.filter.max_level();
+        if log::set_boxed_logger(Box::new(self)).is_ok() {
+            log::set_max_level(max_level);
+        }
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if self.console {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+
+        if let Some(file) = &self.file
+            && let Ok(mut file) = file.lock()
+        {
+            let ts_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as u64);
+            let entry = Entry {
+                ts_ms,
+                level: record.level().as_str(),
+                target: record.target(),
+                message: record.args().to_string(),
+            };
+            if let Ok(line) = serde_json::to_string(&entry) {
+                let _ = writeln!(file, "{}", line);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file
+            && let Ok(mut file) = file.lock()
+        {
+            let _ = file.flush();
+        }
+    }
+}
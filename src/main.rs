@@ -4,52 +4,126 @@
 // - Apprentissage en ligne
 // - Fun / émergent / instable mais stabilisé
 // ------------------------------------------------------------
+// Le REPL n'est qu'un client de la bibliothèque `ai_baby`.
+
+mod cli;
+mod session;
+#[cfg(feature = "tui")]
+mod tui;
+
+use ai_baby::export::{GraphFilter, write_graph};
+use ai_baby::persist::journal::{read_journal, replay};
+use ai_baby::persist::{PersistError, convert, load, save_as};
+use cli::{Command, Options};
+use session::{GREETING, Session};
+
+pub(crate) fn fail(context: &str, e: PersistError) -> ! {
+    eprintln!("{}: {}", context, e);
+    std::process::exit(1);
+}
 
-mod brain;
-mod composition;
-mod modulator;
-mod neuron;
-mod neurotransmitter;
-mod persist;
-mod synapse;
-mod tokenizer;
-use crate::composition::Composition;
-use persist::{load, save};
+fn main() {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            std::process::exit(2);
+        }
+    };
 
-use brain::Brain;
-use brain::{ensure_neuron, ensure_synapse};
-use modulator::Modulator;
-use tokenizer::tokenize;
+    if let Err(msg) = options.init_logging() {
+        eprintln!("{}", msg);
+        std::process::exit(2);
+    }
 
-type Word = String;
+    match &options.command {
+        Command::Repl if options.tui => dashboard(&options),
+        Command::Repl => repl(&options),
+        Command::Convert { src, dst } => {
+            if let Err(e) = convert(src, dst, options.format_for(dst)) {
+                fail("Conversion impossible", e);
+            }
+        }
+        Command::Replay {
+            seed,
+            journal,
+            until,
+            out,
+        } => replay_journal(&options, seed, journal, *until, out.as_deref()),
+        Command::Graph { dst, filter } => export(&options, dst, filter),
+    }
+}
 
-fn main() {
-    let brain_path = "baby_brain.json";
+/// Exporte le réseau du cerveau sauvegardé.
+fn export(options: &Options, dst: &str, filter: &GraphFilter) {
+    let brain = options
+        .autosave()
+        .load()
+        .unwrap_or_else(|e| fail("Cerveau illisible", e));
+    if let Some((word, _)) = &filter.ego
+        && brain.vocab.id(word).is_none()
+    {
+        eprintln!("Mot inconnu du bébé: {}", word);
+        std::process::exit(1);
+    }
+    if let Err(e) = write_graph(&brain, dst, filter) {
+        fail("Export du graphe impossible", e.into());
+    }
+}
 
-    let mut brain = load(brain_path).unwrap_or_else(|| {
-        let mut b = Brain::new();
-        let initial_words = vec!["maman", "papa", "bébé", "amour", "calme", "joie"];
-        for word in &initial_words {
-            ensure_neuron(&mut b.neurons, word, Composition::default()); // Composition par défaut
-        }
-        for i in 0..initial_words.len() {
-            for j in i + 1..initial_words.len() {
-                ensure_synapse(
-                    &mut b.synapses,
-                    initial_words[i],
-                    initial_words[j],
-                    neurotransmitter::Neurotransmitter::Glutamate,
-                );
-            }
+/// Reconstruit un cerveau à partir d'un snapshot de départ et d'un journal.
+fn replay_journal(
+    options: &Options,
+    seed: &str,
+    journal: &str,
+    until: Option<u64>,
+    out: Option<&str>,
+) {
+    let seed = load(seed).unwrap_or_else(|e| fail("Snapshot de départ illisible", e));
+    let entries = read_journal(journal).unwrap_or_else(|e| fail("Journal illisible", e));
+
+    let report = replay(seed, &entries, until, |turn, journaled, replayed| {
+        println!("#{} Toi > {}", turn, journaled.input);
+        println!("#{} Lui > {}", turn, replayed.reply);
+        if replayed.reply != journaled.reply {
+            println!("#{}   ≠ journal : {}", turn, journaled.reply);
         }
-        b
     });
 
-    println!("🍼 Bébé neuronal réveillé.");
-    println!("Parle-lui. Ctrl+C pour arrêter.");
-    println!("(il apprend tout, même la merde 😈)\n");
+    println!(
+        "[Replay] {} événements rejoués, {} divergences",
+        report.turns_replayed,
+        report.divergences.len()
+    );
+
+    if let Some(out) = out
+        && let Err(e) = save_as(&report.brain, out, options.format_for(out))
+    {
+        fail("Sauvegarde du cerveau rejoué impossible", e);
+    }
+}
+
+#[cfg(feature = "tui")]
+fn dashboard(options: &Options) {
+    let mut session = Session::open(options);
+    if let Err(e) = tui::run(&mut session) {
+        eprintln!("Tableau de bord interrompu: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(feature = "tui"))]
+fn dashboard(_: &Options) {
+    unreachable!("--tui est refusé sans la fonctionnalité tui");
+}
 
-    let mut last_reply_words: Vec<Word> = vec![];
+fn repl(options: &Options) {
+    let mut session = Session::open(options);
+
+    for line in GREETING {
+        println!("{}", line);
+    }
+    println!();
 
     loop {
         // -------- input user --------
@@ -58,74 +132,16 @@ fn main() {
         let _ = io::stdout().flush();
 
         let mut input = String::new();
-        if io::stdin().read_line(&mut input).is_err() {
-            continue;
+        match io::stdin().read_line(&mut input) {
+            Ok(0) => break, // fin de l'entrée
+            Ok(_) => {}
+            Err(_) => continue,
         }
-        let input = input.trim().to_string();
+        let input = input.trim();
         if input.is_empty() {
             continue;
         }
 
-        if input == "/reset" {
-            brain = Brain::new();
-            println!("Lui > … (reset total)");
-            continue;
-        }
-        if input == "/save" {
-            save(&brain, brain_path);
-            println!("Lui > (je me suis sauvegardé.)");
-            continue;
-        }
-
-        // -------- feedback implicite sur ce que tu dis --------
-        brain.update_modulator_from_feedback(&input);
-
-        println!("[Main] Modulateur après feedback: {:?}", brain.modulator);
-
-        // -------- spiking / apprentissage --------
-        let words = tokenize(&input);
-
-        // On inclut les mots de sa dernière réponse dans l'apprentissage
-        // pour renforcer ce qui a été "utilisé" en conversation.
-        let mut seed_words = words.clone();
-        seed_words.extend(last_reply_words.clone());
-
-        let spikes_history = brain.run_spiking(&seed_words, 1); // Un seul tick par interaction
-        brain.learn_from_spikes(&spikes_history);
-
-        println!(
-            "[Main] État du cerveau après apprentissage: {} neurones, {} synapses",
-            brain.neurons.len(),
-            brain.synapses.len()
-        );
-
-        // -------- génération réponse --------
-        let reply = brain.generate_reply(&words);
-        println!("Lui > {}", reply);
-
-        // Inclure la réponse dans l'apprentissage
-        let reply_words = tokenize(&reply);
-        let spikes_history = brain.run_spiking(&reply_words, 1); // Tick pour la réponse
-        brain.learn_from_spikes(&spikes_history);
-
-        last_reply_words = reply_words;
-
-        println!(
-            "[Main] État du cerveau après intégration de la réponse: {} neurones, {} synapses",
-            brain.neurons.len(),
-            brain.synapses.len()
-        );
-
-        // -------- feedback explicite --------
-        brain.apply_feedback(&input, &last_reply_words);
-
-        // -------- autosave léger --------
-        save(&brain, brain_path);
-
-        let neurotransmitter = Modulator::detect_neurotransmitter(&input);
-        if let Some(nt) = neurotransmitter {
-            println!("[Main] Neurotransmetteur détecté : {}", nt);
-            brain.modulator.adjust_hormones_for_neurotransmitter(&nt);
-        }
+        println!("Lui > {}", session.handle(input).reply());
     }
 }
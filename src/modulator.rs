@@ -1,5 +1,8 @@
+use crate::logging;
+use log::trace;
 use serde::{Deserialize, Serialize};
 
+/// Chimie globale du bébé : niveaux d'hormones entre 0 et 1.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Modulator {
     pub dopamine: f32,      // renforce l'apprentissage positif
@@ -9,7 +12,14 @@ pub struct Modulator {
     pub endorphins: f32,    // réduit le stress et augmente le plaisir
 }
 
+impl Default for Modulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Modulator {
+    /// Chimie au repos (niveaux basaux).
     pub fn new() -> Self {
         Self {
             dopamine: 0.1,       // Niveau basal de dopamine
@@ -20,9 +30,11 @@ impl Modulator {
         }
     }
 
+    /// Retour progressif vers les niveaux basaux.
     pub fn decay(&mut self) {
-        println!(
-            "[Modulator] Avant décroissance: dopamine = {:.2}, stress = {:.2}, serotonin = {:.2}, noradrenaline = {:.2}, endorphins = {:.2}",
+        trace!(
+            target: logging::MODULATOR,
+            "Avant décroissance: dopamine = {:.2}, stress = {:.2}, serotonin = {:.2}, noradrenaline = {:.2}, endorphins = {:.2}",
             self.dopamine, self.stress, self.serotonin, self.noradrenaline, self.endorphins
         );
 
@@ -32,12 +44,25 @@ impl Modulator {
         self.noradrenaline = (self.noradrenaline * 0.92).max(0.05);
         self.endorphins = (self.endorphins * 0.93).max(0.1);
 
-        println!(
-            "[Modulator] Après décroissance: dopamine = {:.2}, stress = {:.2}, serotonin = {:.2}, noradrenaline = {:.2}, endorphins = {:.2}",
+        trace!(
+            target: logging::MODULATOR,
+            "Après décroissance: dopamine = {:.2}, stress = {:.2}, serotonin = {:.2}, noradrenaline = {:.2}, endorphins = {:.2}",
             self.dopamine, self.stress, self.serotonin, self.noradrenaline, self.endorphins
         );
     }
 
+    /// Sensibilité au feedback de score `score` : la dopamine et les
+    /// endorphines amplifient l'éloge, le stress et la noradrénaline le
+    /// reproche ; la sérotonine amortit les deux.
+    pub fn feedback_gain(&self, score: f32) -> f32 {
+        let arousal = if score >= 0.0 {
+            self.dopamine + self.endorphins
+        } else {
+            self.stress + self.noradrenaline
+        };
+        (1.0 + arousal) / (1.0 + self.serotonin)
+    }
+
     pub fn modulate_neurotransmitter(&self, neurotransmitter: &str) -> f32 {
         match neurotransmitter {
             "glutamate" => self.dopamine * 1.2, // Dopamine renforce glutamate
@@ -1,6 +1,9 @@
 use crate::composition::Composition;
+use crate::neuron_model::NeuronModelKind;
 use serde::{Deserialize, Serialize};
 
+/// Neurone-mot. Sa dynamique est celle du modèle du cerveau
+/// ([`crate::Brain::neuron_model`]), sauf si `model` en impose un autre.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Neuron {
     pub potential: f32,           // Potentiel électrique
@@ -10,6 +13,20 @@ pub struct Neuron {
     pub v: f32,                   // Potentiel membranaire
     pub fired_count: u32,         // Compteur de spikes
     pub leak: f32,                // Taux de fuite
+    #[serde(default)]
+    pub u: f32, // Récupération / adaptation (saut de seuil pour LIF)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<NeuronModelKind>, // Modèle propre à ce neurone
+    #[serde(default)]
+    pub rate: f32, // Taux de décharge estimé, en spikes par tick
+    #[serde(default = "unit_gain")]
+    pub gain: f32, // Gain appliqué à l'entrée synaptique
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_rate: Option<f32>, // Taux visé, sinon celui du cerveau
+}
+
+fn unit_gain() -> f32 {
+    1.0
 }
 
 impl Neuron {
@@ -22,6 +39,11 @@ impl Neuron {
             v: 0.0,
             fired_count: 0,
             leak: 0.1,
+            u: 0.0,
+            model: None,
+            rate: 0.0,
+            gain: 1.0,
+            target_rate: None,
         }
     }
 }
@@ -0,0 +1,321 @@
+// Dynamiques de neurone interchangeables.
+//
+// Le potentiel `v` d'un neurone reste normalisé quel que soit le modèle :
+// 0 au repos, le seuil du neurone au pic du spike. Le reste du cerveau
+// (activité > 0.9, oscillations, rythme circadien, homéostasie des seuils)
+// fonctionne donc tel quel ; chaque modèle convertit `v` dans ses propres
+// unités le temps d'un tick. Un tick vaut 1 ms.
+
+use crate::neuron::Neuron;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Ce qu'un modèle de neurone doit savoir faire pendant un tick.
+pub trait NeuronModel {
+    /// Un tick d'intégration : dynamique propre du modèle plus l'entrée
+    /// synaptique reçue.
+    fn integrate(&self, n: &mut Neuron, input: f32);
+
+    /// Le neurone émet-il un spike ?
+    fn fires(&self, n: &Neuron) -> bool {
+        n.v > n.threshold
+    }
+
+    /// État juste après un spike.
+    fn reset(&self, n: &mut Neuron);
+
+    /// Nombre de ticks pendant lesquels le neurone n'intègre plus rien après
+    /// un spike.
+    fn refractory(&self) -> u32;
+
+    /// Sans nouvelle entrée, le neurone ne peut plus spiker : la simulation
+    /// événementielle peut cesser de le suivre.
+    fn settled(&self, n: &Neuron) -> bool;
+}
+
+/// Intègre-et-tire à fuite : le modèle historique du bébé, avec adaptation
+/// de la fréquence de décharge (chaque spike relève le seuil, `u`, qui
+/// redescend ensuite).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Lif {
+    pub refractory: u32,
+    pub adaptation: f32, // Saut du seuil après un spike (0 : pas d'adaptation)
+    pub adaptation_tau: f32, // Constante de temps du retour, en ticks
+}
+
+impl Default for Lif {
+    fn default() -> Self {
+        Self {
+            refractory: 2,
+            adaptation: 0.2,
+            adaptation_tau: 50.0,
+        }
+    }
+}
+
+impl NeuronModel for Lif {
+    fn integrate(&self, n: &mut Neuron, input: f32) {
+        n.v = n.v * (1.0 - n.leak) + input;
+        n.u -= n.u / self.adaptation_tau.max(1.0);
+    }
+
+    fn fires(&self, n: &Neuron) -> bool {
+        n.v > n.threshold + n.u
+    }
+
+    fn reset(&self, n: &mut Neuron) {
+        n.v = 0.0;
+        n.u += self.adaptation;
+    }
+
+    fn refractory(&self) -> u32 {
+        self.refractory
+    }
+
+    // En dessous de 0.5, la fuite l'emporte sur l'oscillation globale
+    fn settled(&self, n: &Neuron) -> bool {
+        n.v <= 0.5
+    }
+}
+
+const IZH_REST: f32 = -65.0; // mV
+const IZH_PEAK: f32 = 30.0; // mV
+const IZH_INPUT_GAIN: f32 = 10.0; // Entrée 1.0 → courant de 10
+
+/// Modèle d'Izhikevich (2003) : deux variables, une large palette de
+/// comportements selon (a, b, c, d).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Izhikevich {
+    pub a: f32, // Vitesse de récupération
+    pub b: f32, // Sensibilité de la récupération au potentiel
+    pub c: f32, // Potentiel après spike, en mV
+    pub d: f32, // Saut de récupération après spike
+}
+
+impl Izhikevich {
+    /// Neurone cortical typique : spikes réguliers qui s'espacent.
+    pub fn regular_spiking() -> Self {
+        Self {
+            a: 0.02,
+            b: 0.2,
+            c: -65.0,
+            d: 8.0,
+        }
+    }
+
+    /// Salve initiale puis spikes isolés (intrinsically bursting).
+    pub fn bursting() -> Self {
+        Self {
+            a: 0.02,
+            b: 0.2,
+            c: -55.0,
+            d: 4.0,
+        }
+    }
+
+    /// Salves rapides et répétées (chattering).
+    pub fn chattering() -> Self {
+        Self {
+            a: 0.02,
+            b: 0.2,
+            c: -50.0,
+            d: 2.0,
+        }
+    }
+
+    // `u` est stocké comme écart à sa valeur d'équilibre au repos
+    fn u_rest(&self) -> f32 {
+        self.b * IZH_REST
+    }
+}
+
+impl Default for Izhikevich {
+    fn default() -> Self {
+        Self::regular_spiking()
+    }
+}
+
+// Conversions entre `v` normalisé (pic = seuil du neurone) et millivolts
+fn to_mv(n: &Neuron, rest: f32, peak: f32) -> f32 {
+    rest + n.v / n.threshold.max(0.1) * (peak - rest)
+}
+
+fn from_mv(n: &Neuron, mv: f32, rest: f32, peak: f32) -> f32 {
+    (mv - rest) / (peak - rest) * n.threshold.max(0.1)
+}
+
+impl NeuronModel for Izhikevich {
+    fn integrate(&self, n: &mut Neuron, input: f32) {
+        let mut v = to_mv(n, IZH_REST, IZH_PEAK);
+        let mut u = self.u_rest() + n.u;
+        let current = input * IZH_INPUT_GAIN;
+
+        // Deux demi-pas pour v, comme dans l'article, pour la stabilité
+        for _ in 0..2 {
+            v += 0.5 * (0.04 * v * v + 5.0 * v + 140.0 - u + current);
+            v = v.min(IZH_PEAK);
+        }
+        u += self.a * (self.b * v - u);
+
+        n.v = from_mv(n, v, IZH_REST, IZH_PEAK);
+        n.u = u - self.u_rest();
+    }
+
+    fn fires(&self, n: &Neuron) -> bool {
+        n.v >= n.threshold
+    }
+
+    fn reset(&self, n: &mut Neuron) {
+        n.v = from_mv(n, self.c, IZH_REST, IZH_PEAK);
+        n.u += self.d;
+    }
+
+    fn refractory(&self) -> u32 {
+        0
+    }
+
+    // Proche du repos : sous le seuil de l'onde de dépolarisation
+    fn settled(&self, n: &Neuron) -> bool {
+        to_mv(n, IZH_REST, IZH_PEAK) < -60.0 && n.u.abs() < 1.0
+    }
+}
+
+const ADEX_INPUT_GAIN: f32 = 1000.0; // Entrée 1.0 → 1 nA
+const ADEX_SUBSTEPS: usize = 4; // Le terme exponentiel est raide
+
+/// Intègre-et-tire exponentiel adaptatif (Brette & Gerstner 2005).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdEx {
+    pub c_m: f32,     // Capacité membranaire, pF
+    pub g_l: f32,     // Conductance de fuite, nS
+    pub e_l: f32,     // Potentiel de repos, mV
+    pub v_t: f32,     // Seuil effectif, mV
+    pub delta_t: f32, // Raideur de l'exponentielle, mV
+    pub v_peak: f32,  // Pic du spike, mV
+    pub v_reset: f32, // Potentiel après spike, mV
+    pub tau_w: f32,   // Constante de temps de l'adaptation, ms
+    pub a: f32,       // Adaptation sous le seuil, nS
+    pub b: f32,       // Saut d'adaptation après spike, pA
+}
+
+impl Default for AdEx {
+    fn default() -> Self {
+        Self {
+            c_m: 281.0,
+            g_l: 30.0,
+            e_l: -70.6,
+            v_t: -50.4,
+            delta_t: 2.0,
+            v_peak: 20.0,
+            v_reset: -70.6,
+            tau_w: 144.0,
+            a: 4.0,
+            b: 80.5,
+        }
+    }
+}
+
+impl NeuronModel for AdEx {
+    fn integrate(&self, n: &mut Neuron, input: f32) {
+        let mut v = to_mv(n, self.e_l, self.v_peak);
+        let mut w = n.u;
+        let current = input * ADEX_INPUT_GAIN;
+        let dt = 1.0 / ADEX_SUBSTEPS as f32;
+
+        for _ in 0..ADEX_SUBSTEPS {
+            // Exposant borné : au-delà, le pic est de toute façon atteint
+            let spike = self.g_l * self.delta_t * ((v - self.v_t) / self.delta_t).min(20.0).exp();
+            let dv = (-self.g_l * (v - self.e_l) + spike - w + current) / self.c_m;
+            let dw = (self.a * (v - self.e_l) - w) / self.tau_w;
+            v = (v + dt * dv).min(self.v_peak);
+            w += dt * dw;
+        }
+
+        n.v = from_mv(n, v, self.e_l, self.v_peak);
+        n.u = w;
+    }
+
+    fn fires(&self, n: &Neuron) -> bool {
+        n.v >= n.threshold
+    }
+
+    fn reset(&self, n: &mut Neuron) {
+        n.v = from_mv(n, self.v_reset, self.e_l, self.v_peak);
+        n.u += self.b;
+    }
+
+    fn refractory(&self) -> u32 {
+        0
+    }
+
+    fn settled(&self, n: &Neuron) -> bool {
+        to_mv(n, self.e_l, self.v_peak) < self.v_t - 5.0 * self.delta_t && n.u.abs() < 1.0
+    }
+}
+
+/// Choix du modèle, pour tout le cerveau ([`crate::Brain::neuron_model`]) ou
+/// pour un neurone ([`Neuron::model`]).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+pub enum NeuronModelKind {
+    Lif(Lif),
+    Izhikevich(Izhikevich),
+    #[serde(rename = "adex")]
+    AdEx(AdEx),
+}
+
+impl Default for NeuronModelKind {
+    fn default() -> Self {
+        Self::Lif(Lif::default())
+    }
+}
+
+/// Modèle désigné par son nom court : `lif`, `rs`, `ib`, `ch` ou `adex`.
+impl FromStr for NeuronModelKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lif" => Ok(Self::Lif(Lif::default())),
+            "rs" => Ok(Self::Izhikevich(Izhikevich::regular_spiking())),
+            "ib" => Ok(Self::Izhikevich(Izhikevich::bursting())),
+            "ch" => Ok(Self::Izhikevich(Izhikevich::chattering())),
+            "adex" => Ok(Self::AdEx(AdEx::default())),
+            other => Err(format!("modèle de neurone inconnu: {}", other)),
+        }
+    }
+}
+
+impl NeuronModelKind {
+    fn get(&self) -> &dyn NeuronModel {
+        match self {
+            Self::Lif(m) => m,
+            Self::Izhikevich(m) => m,
+            Self::AdEx(m) => m,
+        }
+    }
+}
+
+impl NeuronModel for NeuronModelKind {
+    fn integrate(&self, n: &mut Neuron, input: f32) {
+        self.get().integrate(n, input)
+    }
+
+    fn fires(&self, n: &Neuron) -> bool {
+        self.get().fires(n)
+    }
+
+    fn reset(&self, n: &mut Neuron) {
+        self.get().reset(n)
+    }
+
+    fn refractory(&self) -> u32 {
+        self.get().refractory()
+    }
+
+    fn settled(&self, n: &Neuron) -> bool {
+        self.get().settled(n)
+    }
+}
@@ -0,0 +1,160 @@
+// Normalisation du texte avant le découpage en unités.
+//
+// Un même mot peut arriver sous plusieurs formes : composé ou décomposé en
+// Unicode (« é » en un ou deux caractères), avec ou sans accents, collé à
+// son article élidé (« l'amour »). Les emoji, eux, disparaissaient au
+// découpage alors qu'ils disent beaucoup. La normalisation ramène tout cela
+// à une forme unique, réglable cerveau par cerveau.
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+// Articles et pronoms élidés détachés de leur mot : « l'amour » → « l' amour »
+const ELISIONS: &[&str] = &["l", "d", "j", "qu", "m", "t", "s", "n", "c"];
+
+// Emoji connus et leur mot ; les autres deviennent `emoji_<code>`
+const EMOJI: &[(&str, &str)] = &[
+    ("👍", "emoji_pouce"),
+    ("👎", "emoji_pouce_bas"),
+    ("❤", "emoji_coeur"),
+    ("😊", "emoji_sourire"),
+    ("🙂", "emoji_sourire"),
+    ("😀", "emoji_sourire"),
+    ("😃", "emoji_sourire"),
+    ("😄", "emoji_sourire"),
+    ("😂", "emoji_rire"),
+    ("🤣", "emoji_rire"),
+    ("😍", "emoji_amour"),
+    ("🥰", "emoji_amour"),
+    ("😢", "emoji_triste"),
+    ("😭", "emoji_triste"),
+    ("😞", "emoji_triste"),
+    ("🙁", "emoji_triste"),
+    ("☹", "emoji_triste"),
+    ("😠", "emoji_colere"),
+    ("😡", "emoji_colere"),
+    ("👏", "emoji_bravo"),
+    ("🎉", "emoji_fete"),
+    ("🍼", "emoji_biberon"),
+    ("😈", "emoji_diable"),
+    ("💩", "emoji_caca"),
+];
+
+/// Forme Unicode visée.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnicodeForm {
+    /// Texte laissé tel quel.
+    Keep,
+    /// Caractères composés : « é » en un seul caractère.
+    #[default]
+    Nfc,
+    /// Caractères décomposés : « e » suivi de l'accent.
+    Nfd,
+}
+
+/// Réglages de la normalisation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Normalization {
+    pub form: UnicodeForm,
+    pub fold_accents: bool,  // « bébé » → « bebe »
+    pub split_elision: bool, // « l'amour » → « l' amour »
+    pub emoji: bool,         // 👍 → « emoji_pouce »
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Self {
+            form: UnicodeForm::Nfc,
+            fold_accents: false,
+            split_elision: true,
+            emoji: true,
+        }
+    }
+}
+
+// Pictogrammes des blocs emoji usuels
+fn is_emoji(c: char) -> bool {
+    matches!(c as u32, 0x1F300..=0x1FAFF | 0x2600..=0x27BF)
+}
+
+// Variantes de présentation et liants, sans sens propre
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32, 0xFE0E | 0xFE0F | 0x200D | 0x1F3FB..=0x1F3FF)
+}
+
+impl Normalization {
+    /// Le texte normalisé, prêt pour un [`crate::Tokenizer`].
+    pub fn apply(&self, text: &str) -> String {
+        let mut text = text.replace('’', "'");
+        if self.emoji {
+            text = emoji_to_words(&text);
+        }
+        if self.fold_accents {
+            text = text.nfd().filter(|&c| !is_combining_mark(c)).collect();
+        }
+        text = match self.form {
+            UnicodeForm::Keep => text,
+            UnicodeForm::Nfc => text.nfc().collect(),
+            UnicodeForm::Nfd => text.nfd().collect(),
+        };
+        if self.split_elision {
+            text = split_elisions(&text);
+        }
+        text
+    }
+}
+
+fn emoji_to_words(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if is_emoji_modifier(c) {
+            continue;
+        }
+        if !is_emoji(c) {
+            out.push(c);
+            continue;
+        }
+        let mut buf = [0; 4];
+        let word = EMOJI
+            .iter()
+            .find(|(emoji, _)| *emoji == c.encode_utf8(&mut buf))
+            .map(|(_, word)| word.to_string())
+            .unwrap_or_else(|| format!("emoji_{:x}", c as u32));
+        out.push(' ');
+        out.push_str(&word);
+        out.push(' ');
+    }
+    out
+}
+
+// Chaque mot (lettres, chiffres, apostrophes) est traité à part : espaces,
+// tabulations et ponctuation restent en place
+fn split_elisions(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut word = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() || c == '\'' || is_combining_mark(c) {
+            word.push(c);
+        } else {
+            out.push_str(&split_elision(&word));
+            word.clear();
+            out.push(c);
+        }
+    }
+    out.push_str(&split_elision(&word));
+    out
+}
+
+// « qu'il » → « qu' il » ; « aujourd'hui » et « jusqu'à » restent entiers
+fn split_elision(chunk: &str) -> String {
+    if let Some((head, rest)) = chunk.split_once('\'')
+        && !rest.is_empty()
+        && ELISIONS.contains(&head.to_lowercase().as_str())
+    {
+        return format!("{}' {}", head, split_elision(rest));
+    }
+    chunk.to_string()
+}
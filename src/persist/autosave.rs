@@ -0,0 +1,126 @@
+// Autosave résistant aux plantages : écriture atomique et sauvegardes
+// tournantes `baby_brain.json.1`, `.2`, ... (la `.1` est la plus récente).
+
+use crate::brain::Brain;
+use crate::logging;
+use crate::persist::persistence::{Format, read_snapshot, with_suffix, write_snapshot};
+use crate::persist::{BrainSnapshot, PersistError};
+use log::warn;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nombre de sauvegardes tournantes conservées par défaut.
+pub const DEFAULT_BACKUPS: usize = 3;
+
+/// Emplacement de sauvegarde d'un cerveau, avec ses sauvegardes de secours.
+#[derive(Clone, Debug)]
+pub struct Autosave {
+    pub path: PathBuf,
+    pub format: Format,
+    pub backups: usize,
+}
+
+impl Autosave {
+    /// Format déduit de l'extension, [`DEFAULT_BACKUPS`] sauvegardes.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        Self {
+            format: Format::from_path(&path),
+            path,
+            backups: DEFAULT_BACKUPS,
+        }
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_backups(mut self, backups: usize) -> Self {
+        self.backups = backups;
+        self
+    }
+
+    /// Chemin de la `n`-ième sauvegarde (1 = la plus récente).
+    pub fn backup_path(&self, n: usize) -> PathBuf {
+        with_suffix(&self.path, &format!(".{}", n))
+    }
+
+    /// Sauvegarde atomique : `.1` devient `.2`, etc., la plus ancienne
+    /// au-delà de `backups` étant écrasée, puis le fichier courant est copié
+    /// en `.1` avant d'être remplacé. Le fichier principal reste en place
+    /// jusqu'au renommage final : un arrêt brutal ne le fait jamais manquer.
+    pub fn save(&self, brain: &Brain) -> Result<(), PersistError> {
+        if self.backups > 0 && self.path.exists() {
+            for n in (1..self.backups).rev() {
+                rename_if_exists(&self.backup_path(n), &self.backup_path(n + 1))?;
+            }
+            keep_copy(&self.path, &self.backup_path(1))?;
+        }
+        write_snapshot(BrainSnapshot::from(brain), &self.path, self.format)
+    }
+
+    /// Charge le fichier principal ; s'il est absent ou illisible, se rabat
+    /// sur la sauvegarde valide la plus récente. En cas d'échec général,
+    /// renvoie l'erreur du fichier principal.
+    pub fn load(&self) -> Result<Brain, PersistError> {
+        self.recover().map(|(brain, _)| brain)
+    }
+
+    /// Comme [`Autosave::load`], en indiquant la sauvegarde de secours
+    /// utilisée (`None` si le fichier principal était bon).
+    pub fn recover(&self) -> Result<(Brain, Option<PathBuf>), PersistError> {
+        let primary_err = match read_snapshot(&self.path) {
+            Ok(snapshot) => return Ok((snapshot.into(), None)),
+            Err(e) => e,
+        };
+
+        for n in 1..=self.backups {
+            let backup = self.backup_path(n);
+            match read_snapshot(&backup) {
+                Ok(snapshot) => {
+                    warn!(
+                        target: logging::PERSIST,
+                        "{} inutilisable ({}), reprise depuis {}",
+                        self.path.display(),
+                        primary_err,
+                        backup.display()
+                    );
+                    return Ok((snapshot.into(), Some(backup)));
+                }
+                Err(e) if e.is_not_found() => {}
+                Err(e) => {
+                    warn!(
+                        target: logging::PERSIST,
+                        "Sauvegarde {} ignorée: {}",
+                        backup.display(),
+                        e
+                    );
+                }
+            }
+        }
+
+        Err(primary_err)
+    }
+}
+
+// Lien physique si possible (le renommage atomique qui suit détache le
+// fichier principal), copie sinon
+fn keep_copy(from: &Path, to: &Path) -> Result<(), PersistError> {
+    match fs::remove_file(to) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+        _ => {}
+    }
+    if fs::hard_link(from, to).is_err() {
+        fs::copy(from, to)?;
+    }
+    Ok(())
+}
+
+fn rename_if_exists(from: &Path, to: &Path) -> Result<(), PersistError> {
+    match fs::rename(from, to) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        other => Ok(other?),
+    }
+}
@@ -0,0 +1,223 @@
+// Format binaire compact : MessagePack précédé d'un nombre magique.
+// Chaque mot n'est écrit qu'une fois dans une table partagée par les
+// neurones, les synapses et la dernière réponse ; le reste y fait référence
+// par indice. Les neurones sont internés en premier : la table reprend
+// l'ordre du vocabulaire.
+
+use crate::brain::{
+    Age, DevelopmentParams, GenerationParams, HomeostasisParams, RewardParams, SimulationMode,
+    StdpParams,
+};
+use crate::modulator::Modulator;
+use crate::neuron::Neuron;
+use crate::neuron_model::NeuronModelKind;
+use crate::normalize::Normalization;
+use crate::persist::PersistError;
+use crate::persist::snapshot::{self, BrainSnapshot, NeuronEntry, SNAPSHOT_VERSION, SynapseEdge};
+use crate::synapse::Synapse;
+use crate::tokenizer::TokenizerKind;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub type Word = String;
+
+/// En-tête des fichiers binaires, utilisé aussi pour la détection du format.
+pub const MAGIC: &[u8; 4] = b"BBRN";
+
+#[derive(Serialize, Deserialize)]
+struct InternedSnapshot {
+    version: u32,
+    words: Vec<Word>,
+    neurons: Vec<(u32, Neuron)>,
+    synapses: Vec<(u32, u32, Synapse)>,
+    modulator: Modulator,
+
+    // hyperparams
+    lr_exc: f32,
+    lr_inh: f32,
+    exc_max: f32,
+    inh_max: f32,
+    forget: f32,
+    #[serde(default)]
+    stdp: StdpParams,
+    #[serde(default)]
+    homeostasis: HomeostasisParams,
+    #[serde(default)]
+    reward: RewardParams,
+    #[serde(default)]
+    generation: GenerationParams,
+    #[serde(default)]
+    development: DevelopmentParams,
+    #[serde(default)]
+    age: Age,
+    #[serde(default)]
+    tokenizer: TokenizerKind,
+    #[serde(default)]
+    normalization: Normalization,
+    #[serde(default)]
+    simulation: SimulationMode,
+    #[serde(default)]
+    neuron_model: NeuronModelKind,
+
+    #[serde(default)]
+    last_reply_words: Vec<u32>,
+    #[serde(default)]
+    last_reply_synapses: Vec<(u32, u32)>,
+    #[serde(default)]
+    seed: Option<u64>,
+    #[serde(default)]
+    rng: Option<ChaCha8Rng>,
+}
+
+#[derive(Default)]
+struct Interner {
+    words: Vec<Word>,
+    ids: HashMap<Word, u32>,
+}
+
+impl Interner {
+    fn intern(&mut self, w: &str) -> u32 {
+        if let Some(&id) = self.ids.get(w) {
+            return id;
+        }
+        let id = self.words.len() as u32;
+        self.words.push(w.to_string());
+        self.ids.insert(w.to_string(), id);
+        id
+    }
+}
+
+/// Encode un snapshot en binaire (en-tête compris).
+pub fn encode(snapshot: BrainSnapshot) -> Result<Vec<u8>, PersistError> {
+    let mut interner = Interner::default();
+    let neurons = snapshot
+        .neurons
+        .into_iter()
+        .map(|e| (interner.intern(&e.word), e.neuron))
+        .collect();
+    let synapses = snapshot
+        .synapses
+        .into_iter()
+        .map(|e| (interner.intern(&e.pre), interner.intern(&e.post), e.synapse))
+        .collect();
+    let last_reply_words = snapshot
+        .last_reply_words
+        .iter()
+        .map(|w| interner.intern(w))
+        .collect();
+    let last_reply_synapses = snapshot
+        .last_reply_synapses
+        .iter()
+        .map(|(pre, post)| (interner.intern(pre), interner.intern(post)))
+        .collect();
+
+    let interned = InternedSnapshot {
+        version: snapshot.version,
+        words: interner.words,
+        neurons,
+        synapses,
+        modulator: snapshot.modulator,
+        lr_exc: snapshot.lr_exc,
+        lr_inh: snapshot.lr_inh,
+        exc_max: snapshot.exc_max,
+        inh_max: snapshot.inh_max,
+        forget: snapshot.forget,
+        stdp: snapshot.stdp,
+        homeostasis: snapshot.homeostasis,
+        reward: snapshot.reward,
+        generation: snapshot.generation,
+        development: snapshot.development,
+        age: snapshot.age,
+        tokenizer: snapshot.tokenizer,
+        normalization: snapshot.normalization,
+        simulation: snapshot.simulation,
+        neuron_model: snapshot.neuron_model,
+        last_reply_words,
+        last_reply_synapses,
+        seed: snapshot.seed,
+        rng: snapshot.rng,
+    };
+
+    let mut bytes = MAGIC.to_vec();
+    rmp_serde::encode::write_named(&mut bytes, &interned)?;
+    Ok(bytes)
+}
+
+/// Décode un fichier binaire et le remonte à la version courante.
+pub fn decode(bytes: &[u8]) -> Result<BrainSnapshot, PersistError> {
+    let payload = bytes
+        .strip_prefix(MAGIC.as_slice())
+        .ok_or_else(|| PersistError::Corrupt("en-tête binaire absent".into()))?;
+    let interned: InternedSnapshot = rmp_serde::from_slice(payload)?;
+    if interned.version > SNAPSHOT_VERSION {
+        return Err(PersistError::UnsupportedVersion(interned.version));
+    }
+
+    let words = &interned.words;
+    let word = |id: u32| {
+        words
+            .get(id as usize)
+            .cloned()
+            .ok_or_else(|| PersistError::Corrupt(format!("mot #{} hors de la table", id)))
+    };
+
+    let snapshot = BrainSnapshot {
+        version: interned.version,
+        neurons: interned
+            .neurons
+            .into_iter()
+            .map(|(id, neuron)| {
+                Ok(NeuronEntry {
+                    word: word(id)?,
+                    neuron,
+                })
+            })
+            .collect::<Result<_, PersistError>>()?,
+        synapses: interned
+            .synapses
+            .into_iter()
+            .map(|(pre, post, synapse)| {
+                Ok(SynapseEdge {
+                    pre: word(pre)?,
+                    post: word(post)?,
+                    synapse,
+                })
+            })
+            .collect::<Result<_, PersistError>>()?,
+        modulator: interned.modulator,
+        lr_exc: interned.lr_exc,
+        lr_inh: interned.lr_inh,
+        exc_max: interned.exc_max,
+        inh_max: interned.inh_max,
+        forget: interned.forget,
+        stdp: interned.stdp,
+        homeostasis: interned.homeostasis,
+        reward: interned.reward,
+        generation: interned.generation,
+        development: interned.development,
+        age: interned.age,
+        tokenizer: interned.tokenizer,
+        normalization: interned.normalization,
+        simulation: interned.simulation,
+        neuron_model: interned.neuron_model,
+        last_reply_words: interned
+            .last_reply_words
+            .into_iter()
+            .map(word)
+            .collect::<Result<_, _>>()?,
+        last_reply_synapses: interned
+            .last_reply_synapses
+            .into_iter()
+            .map(|(pre, post)| Ok((word(pre)?, word(post)?)))
+            .collect::<Result<_, PersistError>>()?,
+        seed: interned.seed,
+        rng: interned.rng,
+    };
+
+    if snapshot.version == SNAPSHOT_VERSION {
+        Ok(snapshot)
+    } else {
+        snapshot::migrate(serde_json::to_value(snapshot)?)
+    }
+}
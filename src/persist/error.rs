@@ -0,0 +1,71 @@
+use std::fmt;
+use std::io;
+
+/// Erreur de sauvegarde ou de chargement d'un cerveau.
+#[derive(Debug)]
+pub enum PersistError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    BinaryEncode(rmp_serde::encode::Error),
+    BinaryDecode(rmp_serde::decode::Error),
+    UnsupportedVersion(u32),
+    Corrupt(String),
+}
+
+impl PersistError {
+    /// Vrai si le fichier n'existe simplement pas encore (premier réveil).
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, PersistError::Io(e) if e.kind() == io::ErrorKind::NotFound)
+    }
+}
+
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistError::Io(e) => write!(f, "erreur d'entrée/sortie: {}", e),
+            PersistError::Json(e) => write!(f, "JSON invalide: {}", e),
+            PersistError::BinaryEncode(e) => write!(f, "encodage binaire impossible: {}", e),
+            PersistError::BinaryDecode(e) => write!(f, "binaire invalide: {}", e),
+            PersistError::UnsupportedVersion(v) => {
+                write!(f, "version de snapshot non supportée: {}", v)
+            }
+            PersistError::Corrupt(msg) => write!(f, "snapshot corrompu: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PersistError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PersistError::Io(e) => Some(e),
+            PersistError::Json(e) => Some(e),
+            PersistError::BinaryEncode(e) => Some(e),
+            PersistError::BinaryDecode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PersistError {
+    fn from(e: io::Error) -> Self {
+        PersistError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for PersistError {
+    fn from(e: serde_json::Error) -> Self {
+        PersistError::Json(e)
+    }
+}
+
+impl From<rmp_serde::encode::Error> for PersistError {
+    fn from(e: rmp_serde::encode::Error) -> Self {
+        PersistError::BinaryEncode(e)
+    }
+}
+
+impl From<rmp_serde::decode::Error> for PersistError {
+    fn from(e: rmp_serde::decode::Error) -> Self {
+        PersistError::BinaryDecode(e)
+    }
+}
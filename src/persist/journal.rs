@@ -0,0 +1,250 @@
+// Journal d'expérience en ajout seul (une ligne JSON par événement) et
+// rejeu : snapshot de départ + journal = cerveau reconstruit tour par tour.
+// Le snapshot de départ (`<journal>.seed`) est écrit quand le journal
+// commence ; ensuite, tout ce qui change le cerveau hors conversation (remise
+// à zéro, réglages de la ligne de commande, reprise depuis un fichier en
+// retard sur le journal) est journalisé aussi.
+
+use crate::brain::{Brain, Interaction, SimulationMode};
+use crate::logging;
+use crate::neuron_model::NeuronModelKind;
+use crate::normalize::Normalization;
+use crate::persist::persistence::with_suffix;
+use crate::persist::{BrainSnapshot, Format, PersistError, save_as};
+use crate::tokenizer::TokenizerKind;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Réglages qu'on peut imposer à un cerveau existant (`--tokenizer`,
+/// `--sim`…), à rejouer avec lui.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub tokenizer: TokenizerKind,
+    pub normalization: Normalization,
+    pub simulation: SimulationMode,
+    pub neuron_model: NeuronModelKind,
+}
+
+impl Settings {
+    pub fn of(brain: &Brain) -> Self {
+        Self {
+            tokenizer: brain.tokenizer,
+            normalization: brain.normalization.clone(),
+            simulation: brain.simulation,
+            neuron_model: brain.neuron_model,
+        }
+    }
+
+    pub fn apply(&self, brain: &mut Brain) {
+        brain.tokenizer = self.tokenizer;
+        brain.normalization = self.normalization.clone();
+        brain.simulation = self.simulation;
+        brain.neuron_model = self.neuron_model;
+    }
+}
+
+/// Ce qui arrive au bébé pendant une session.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JournalEvent {
+    Interaction(Interaction),
+    /// `/reset` ou cerveau neuf : le cerveau repart de zéro, avec cette
+    /// graine et ces réglages.
+    Reset {
+        #[serde(default)]
+        seed: Option<u64>,
+        #[serde(default)]
+        settings: Option<Settings>,
+    },
+    /// Nouveaux réglages imposés au cerveau rechargé.
+    Configure(Settings),
+    /// Le fichier du cerveau n'est plus au bout du journal (sauvegarde
+    /// ratée, reprise depuis une sauvegarde de secours) : on repart de lui.
+    /// Snapshot JSON dans une chaîne, l'état du générateur (u128) ne passant
+    /// pas par le tag de l'événement.
+    Resume {
+        snapshot: String,
+    },
+}
+
+impl JournalEvent {
+    /// Reprise depuis `brain`.
+    pub fn resume(brain: &Brain) -> Result<Self, PersistError> {
+        Ok(JournalEvent::Resume {
+            snapshot: serde_json::to_string(&BrainSnapshot::from(brain))?,
+        })
+    }
+}
+
+/// Snapshot de départ du journal `path` : `<journal>.seed`.
+pub fn seed_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    with_suffix(path.as_ref(), ".seed")
+}
+
+/// Une ligne du journal, numérotée.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub turn: u64,
+    #[serde(flatten)]
+    pub event: JournalEvent,
+}
+
+/// Journal ouvert en ajout : chaque événement est écrit et vidé sur disque
+/// immédiatement.
+pub struct Journal {
+    path: PathBuf,
+    file: BufWriter<File>,
+    next_turn: u64,
+}
+
+impl Journal {
+    /// Ouvre (ou crée) le journal ; la numérotation reprend après la
+    /// dernière entrée existante.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, PersistError> {
+        let path = path.as_ref().to_path_buf();
+        let next_turn = match read_journal(&path) {
+            Ok(entries) => entries.last().map(|e| e.turn + 1).unwrap_or(0),
+            Err(e) if e.is_not_found() => 0,
+            Err(e) => return Err(e),
+        };
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            file: BufWriter::new(file),
+            next_turn,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Vrai tant qu'aucun événement n'a été écrit.
+    pub fn is_empty(&self) -> bool {
+        self.next_turn == 0
+    }
+
+    /// Écrit le snapshot de départ à côté du journal (voir [`seed_path`]).
+    pub fn write_seed(&self, brain: &Brain) -> Result<(), PersistError> {
+        save_as(brain, seed_path(&self.path), Format::Json)
+    }
+
+    /// Ajoute un événement et renvoie son numéro de tour.
+    pub fn append(&mut self, event: JournalEvent) -> Result<u64, PersistError> {
+        let entry = JournalEntry {
+            turn: self.next_turn,
+            event,
+        };
+        serde_json::to_writer(&mut self.file, &entry)?;
+        self.file.write_all(b"\n")?;
+        self.file.flush()?;
+        self.next_turn += 1;
+        Ok(entry.turn)
+    }
+}
+
+/// Lit toutes les entrées d'un journal. Une dernière ligne tronquée (arrêt
+/// pendant l'écriture) est ignorée ; toute autre ligne invalide est une
+/// erreur.
+pub fn read_journal<P: AsRef<Path>>(path: P) -> Result<Vec<JournalEntry>, PersistError> {
+    let content = fs::read_to_string(path)?;
+    let truncated_tail = !content.is_empty() && !content.ends_with('\n');
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut entries = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(line) {
+            Ok(entry) => entries.push(entry),
+            Err(_) if truncated_tail && i + 1 == lines.len() => {
+                warn!(target: logging::PERSIST, "Dernière ligne du journal tronquée, ignorée");
+            }
+            Err(e) => {
+                return Err(PersistError::Corrupt(format!(
+                    "journal, ligne {}: {}",
+                    i + 1,
+                    e
+                )));
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Un tour rejoué dont la réponse diffère de celle du journal.
+#[derive(Clone, Debug)]
+pub struct Divergence {
+    pub turn: u64,
+    pub journaled: String,
+    pub replayed: String,
+}
+
+/// Résultat d'un rejeu.
+pub struct Replay {
+    pub brain: Brain,
+    pub turns_replayed: usize,
+    pub divergences: Vec<Divergence>,
+}
+
+/// Rejoue `entries` sur `seed`, en s'arrêtant après le tour `until` s'il est
+/// donné (pratique pour bisecter la conversation fautive). `on_turn` est
+/// appelé après chaque interaction rejouée.
+pub fn replay<F>(
+    seed: Brain,
+    entries: &[JournalEntry],
+    until: Option<u64>,
+    mut on_turn: F,
+) -> Replay
+where
+    F: FnMut(u64, &Interaction, &Interaction),
+{
+    let mut brain = seed;
+    let mut turns_replayed = 0;
+    let mut divergences = vec![];
+
+    for entry in entries {
+        if until.is_some_and(|last| entry.turn > last) {
+            break;
+        }
+        match &entry.event {
+            JournalEvent::Reset { seed, settings } => {
+                brain = seed.map(Brain::with_seed).unwrap_or_default();
+                if let Some(settings) = settings {
+                    settings.apply(&mut brain);
+                }
+            }
+            JournalEvent::Configure(settings) => settings.apply(&mut brain),
+            JournalEvent::Resume { snapshot } => {
+                match serde_json::from_str::<BrainSnapshot>(snapshot) {
+                    Ok(snapshot) => brain = snapshot.into(),
+                    Err(e) => {
+                        warn!(target: logging::PERSIST, "Tour {}: reprise illisible: {}", entry.turn, e)
+                    }
+                }
+            }
+            JournalEvent::Interaction(journaled) => {
+                let replayed = brain.experience(&journaled.input);
+                if replayed.reply != journaled.reply {
+                    divergences.push(Divergence {
+                        turn: entry.turn,
+                        journaled: journaled.reply.clone(),
+                        replayed: replayed.reply.clone(),
+                    });
+                }
+                on_turn(entry.turn, journaled, &replayed);
+            }
+        }
+        turns_replayed += 1;
+    }
+
+    Replay {
+        brain,
+        turns_replayed,
+        divergences,
+    }
+}
@@ -1,3 +1,13 @@
-pub use persistence::{load, save};
+pub use autosave::{Autosave, DEFAULT_BACKUPS};
+pub use error::PersistError;
+pub use persistence::{
+    Format, convert, load, read_snapshot, save, save_as, write_atomic, write_snapshot,
+};
+pub use snapshot::{BrainSnapshot, SNAPSHOT_VERSION};
 
+pub mod autosave;
+pub mod binary;
+pub mod error;
+pub mod journal;
 pub mod persistence;
+pub mod snapshot;
@@ -1,13 +1,125 @@
-use serde::{Deserialize, Serialize};
+use crate::brain::Brain;
+use crate::persist::snapshot::{self, BrainSnapshot};
+use crate::persist::{PersistError, binary};
+use std::ffi::OsString;
 use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-pub fn load<T: for<'de> Deserialize<'de>>(path: &str) -> Option<T> {
-    let s = fs::read_to_string(path).ok()?;
-    serde_json::from_str(&s).ok()
+/// Format de fichier d'un snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// JSON lisible, pratique pour inspecter le cerveau.
+    Json,
+    /// Binaire compact avec table de mots partagée.
+    Binary,
 }
 
-pub fn save<T: Serialize>(data: &T, path: &str) {
-    if let Ok(json) = serde_json::to_string_pretty(data) {
-        let _ = fs::write(path, json);
+impl Format {
+    /// Devine le format d'après l'extension : `.bin` ou `.brain` pour le
+    /// binaire, JSON sinon.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some("bin") | Some("brain") => Format::Binary,
+            _ => Format::Json,
+        }
     }
+
+    fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(binary::MAGIC) {
+            Format::Binary
+        } else {
+            Format::Json
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Format::Json),
+            "bin" | "binary" => Ok(Format::Binary),
+            other => Err(format!("format inconnu: {}", other)),
+        }
+    }
+}
+
+/// Lit un snapshot, quel que soit son format (détecté sur le contenu), en
+/// migrant les anciennes versions au passage.
+pub fn read_snapshot<P: AsRef<Path>>(path: P) -> Result<BrainSnapshot, PersistError> {
+    let bytes = fs::read(path)?;
+    match Format::detect(&bytes) {
+        Format::Binary => binary::decode(&bytes),
+        Format::Json => snapshot::migrate(serde_json::from_slice(&bytes)?),
+    }
+}
+
+/// `chemin` + `suffixe` (ex: `baby_brain.json` → `baby_brain.json.1`).
+pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Écrit `bytes` dans un fichier temporaire voisin, le synchronise sur le
+/// disque puis le renomme atomiquement sur `path` : un arrêt brutal laisse
+/// soit l'ancien fichier, soit le nouveau, jamais un mélange.
+pub fn write_atomic<P: AsRef<Path>>(path: P, bytes: &[u8]) -> Result<(), PersistError> {
+    let path = path.as_ref();
+    let tmp = with_suffix(path, ".tmp");
+    {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(bytes)?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path)?;
+
+    // Au mieux : rendre le renommage lui-même durable.
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty())
+        && let Ok(dir) = fs::File::open(dir)
+    {
+        let _ = dir.sync_all();
+    }
+    Ok(())
+}
+
+/// Écrit un snapshot dans le format demandé, de façon atomique.
+pub fn write_snapshot<P: AsRef<Path>>(
+    snapshot: BrainSnapshot,
+    path: P,
+    format: Format,
+) -> Result<(), PersistError> {
+    let bytes = match format {
+        Format::Json => serde_json::to_vec_pretty(&snapshot)?,
+        Format::Binary => binary::encode(snapshot)?,
+    };
+    write_atomic(path, &bytes)
+}
+
+/// Charge un cerveau depuis un snapshot JSON ou binaire.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Brain, PersistError> {
+    Ok(read_snapshot(path)?.into())
+}
+
+/// Sauvegarde le cerveau dans `path`, au format déduit de l'extension.
+pub fn save<P: AsRef<Path>>(brain: &Brain, path: P) -> Result<(), PersistError> {
+    let format = Format::from_path(&path);
+    save_as(brain, path, format)
+}
+
+/// Sauvegarde le cerveau dans `path` au format imposé.
+pub fn save_as<P: AsRef<Path>>(brain: &Brain, path: P, format: Format) -> Result<(), PersistError> {
+    write_snapshot(BrainSnapshot::from(brain), path, format)
+}
+
+/// Convertit un snapshot d'un fichier à l'autre (JSON ↔ binaire).
+pub fn convert<P: AsRef<Path>, Q: AsRef<Path>>(
+    src: P,
+    dst: Q,
+    format: Format,
+) -> Result<(), PersistError> {
+    write_snapshot(read_snapshot(src)?, dst, format)
 }
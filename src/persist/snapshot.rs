@@ -0,0 +1,351 @@
+// Format de sauvegarde versionné du cerveau.
+// Les identifiants de neurones n'existent qu'en mémoire : le snapshot écrit
+// les mots. Les neurones sont listés dans l'ordre du vocabulaire, pour que
+// le cerveau rechargé retrouve les mêmes identifiants, et les synapses en
+// arêtes orientées entre mots.
+
+use crate::brain::{
+    Age, Brain, DevelopmentParams, GenerationParams, HomeostasisParams, Metrics, RewardParams,
+    SimulationMode, StdpParams, SynapseKey, Vocabulary, ensure_neuron,
+};
+use crate::composition::Composition;
+use crate::modulator::Modulator;
+use crate::neuron::Neuron;
+use crate::neuron_model::NeuronModelKind;
+use crate::normalize::Normalization;
+use crate::persist::PersistError;
+use crate::synapse::Synapse;
+use crate::tokenizer::TokenizerKind;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+pub type Word = String;
+
+/// Version courante du schéma de snapshot.
+pub const SNAPSHOT_VERSION: u32 = 3;
+
+/// Un neurone sérialisé avec son mot.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NeuronEntry {
+    pub word: Word,
+    pub neuron: Neuron,
+}
+
+/// Une synapse sérialisée avec ses deux extrémités (pré → post).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SynapseEdge {
+    pub pre: Word,
+    pub post: Word,
+    pub synapse: Synapse,
+}
+
+/// Photographie complète et sans perte d'un [`Brain`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BrainSnapshot {
+    pub version: u32,
+    /// Dans l'ordre des identifiants.
+    pub neurons: Vec<NeuronEntry>,
+    pub synapses: Vec<SynapseEdge>,
+    pub modulator: Modulator,
+
+    // hyperparams
+    pub lr_exc: f32,
+    pub lr_inh: f32,
+    pub exc_max: f32,
+    pub inh_max: f32,
+    pub forget: f32,
+    #[serde(default)]
+    pub stdp: StdpParams,
+    #[serde(default)]
+    pub homeostasis: HomeostasisParams,
+    #[serde(default)]
+    pub reward: RewardParams,
+    #[serde(default)]
+    pub generation: GenerationParams,
+    #[serde(default)]
+    pub development: DevelopmentParams,
+    #[serde(default)]
+    pub age: Age,
+    #[serde(default)]
+    pub tokenizer: TokenizerKind,
+    #[serde(default)]
+    pub normalization: Normalization,
+    #[serde(default)]
+    pub simulation: SimulationMode,
+    #[serde(default)]
+    pub neuron_model: NeuronModelKind,
+
+    #[serde(default)]
+    pub last_reply_words: Vec<Word>,
+    /// Synapses (pré, post) qui ont produit la dernière réponse.
+    #[serde(default)]
+    pub last_reply_synapses: Vec<(Word, Word)>,
+
+    /// Graine et état du générateur aléatoire ; absents des vieux snapshots,
+    /// qui reçoivent alors une graine neuve au chargement.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub rng: Option<ChaCha8Rng>,
+}
+
+impl From<&Brain> for BrainSnapshot {
+    fn from(brain: &Brain) -> Self {
+        let word = |id| brain.vocab.word(id).to_string();
+
+        Self {
+            version: SNAPSHOT_VERSION,
+            neurons: brain
+                .vocab
+                .iter()
+                .map(|(id, w)| {
+                    // Dérive des seuils pas encore reportée sur les neurones
+                    let mut neuron = brain.neurons[id.index()].clone();
+                    if brain.threshold_drift != 0.0 {
+                        neuron.threshold += brain.threshold_drift;
+                    }
+                    NeuronEntry {
+                        word: w.clone(),
+                        neuron,
+                    }
+                })
+                .collect(),
+            synapses: brain
+                .synapses
+                .iter()
+                .map(|(key, syn)| SynapseEdge {
+                    pre: word(key.pre),
+                    post: word(key.post),
+                    synapse: syn.clone(),
+                })
+                .collect(),
+            modulator: brain.modulator.clone(),
+            lr_exc: brain.lr_exc,
+            lr_inh: brain.lr_inh,
+            exc_max: brain.exc_max,
+            inh_max: brain.inh_max,
+            forget: brain.forget,
+            stdp: brain.stdp.clone(),
+            homeostasis: brain.homeostasis.clone(),
+            reward: brain.reward.clone(),
+            generation: brain.generation.clone(),
+            development: brain.development.clone(),
+            age: brain.age.clone(),
+            tokenizer: brain.tokenizer,
+            normalization: brain.normalization.clone(),
+            simulation: brain.simulation,
+            neuron_model: brain.neuron_model,
+            last_reply_words: brain.last_reply_words.clone(),
+            last_reply_synapses: brain
+                .last_reply_synapses
+                .iter()
+                .map(|key| (word(key.pre), word(key.post)))
+                .collect(),
+            seed: Some(brain.seed),
+            rng: Some(brain.rng.clone()),
+        }
+    }
+}
+
+impl From<BrainSnapshot> for Brain {
+    fn from(snapshot: BrainSnapshot) -> Self {
+        let seed = snapshot.seed.unwrap_or_else(rand::random);
+        let rng = snapshot
+            .rng
+            .unwrap_or_else(|| ChaCha8Rng::seed_from_u64(seed));
+
+        let mut vocab = Vocabulary::new();
+        let mut neurons = Vec::new();
+        for entry in snapshot.neurons {
+            let id = ensure_neuron(
+                &mut vocab,
+                &mut neurons,
+                &entry.word,
+                Composition::default(),
+            );
+            neurons[id.index()] = entry.neuron;
+        }
+        // Une extrémité sans neurone (fichier édité à la main) en reçoit un neuf
+        let synapses = snapshot
+            .synapses
+            .into_iter()
+            .map(|e| {
+                let mut id =
+                    |w: &str| ensure_neuron(&mut vocab, &mut neurons, w, Composition::default());
+                (SynapseKey::new(id(&e.pre), id(&e.post)), e.synapse)
+            })
+            .collect();
+        let last_reply_synapses = snapshot
+            .last_reply_synapses
+            .iter()
+            .filter_map(|(pre, post)| Some(SynapseKey::new(vocab.id(pre)?, vocab.id(post)?)))
+            .collect();
+
+        Brain {
+            vocab,
+            neurons,
+            synapses,
+            modulator: snapshot.modulator,
+            lr_exc: snapshot.lr_exc,
+            lr_inh: snapshot.lr_inh,
+            exc_max: snapshot.exc_max,
+            inh_max: snapshot.inh_max,
+            forget: snapshot.forget,
+            stdp: snapshot.stdp,
+            homeostasis: snapshot.homeostasis,
+            reward: snapshot.reward,
+            generation: snapshot.generation,
+            development: snapshot.development,
+            age: snapshot.age,
+            tokenizer: snapshot.tokenizer,
+            normalization: snapshot.normalization,
+            simulation: snapshot.simulation,
+            neuron_model: snapshot.neuron_model,
+            last_reply_words: snapshot.last_reply_words,
+            last_reply_synapses,
+            metrics: Metrics::default(),
+            threshold_drift: 0.0,
+            events: None,
+            seed,
+            rng,
+        }
+    }
+}
+
+/// Remonte un document JSON de n'importe quelle version connue jusqu'à
+/// [`SNAPSHOT_VERSION`]. Un document sans champ `version` est considéré
+/// comme un ancien `Brain` sérialisé directement (version 0).
+pub fn migrate(mut doc: Value) -> Result<BrainSnapshot, PersistError> {
+    let mut version = match doc.get("version") {
+        None => 0,
+        Some(v) => v
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| PersistError::Corrupt(format!("champ version invalide: {}", v)))?,
+    };
+    if version > SNAPSHOT_VERSION {
+        return Err(PersistError::UnsupportedVersion(version));
+    }
+
+    while version < SNAPSHOT_VERSION {
+        doc = match version {
+            0 => migrate_v0_to_v1(doc)?,
+            1 => migrate_v1_to_v2(doc)?,
+            2 => migrate_v2_to_v3(doc)?,
+            _ => return Err(PersistError::UnsupportedVersion(version)),
+        };
+        version += 1;
+    }
+
+    Ok(serde_json::from_value(doc)?)
+}
+
+// v0 : `Brain` sérialisé tel quel. Les synapses, quand elles ont pu être
+// écrites, sont une liste de paires `[[a, b], synapse]`.
+fn migrate_v0_to_v1(mut doc: Value) -> Result<Value, PersistError> {
+    let obj = doc
+        .as_object_mut()
+        .ok_or_else(|| PersistError::Corrupt("le document n'est pas un objet".into()))?;
+
+    let edges = match obj.remove("synapses") {
+        None | Some(Value::Null) => vec![],
+        Some(Value::Array(pairs)) => pairs
+            .into_iter()
+            .map(|pair| match pair {
+                Value::Array(mut kv) if kv.len() == 2 => {
+                    let synapse = kv.pop().unwrap_or_default();
+                    match kv.pop() {
+                        Some(Value::Array(ends)) if ends.len() == 2 => Ok(serde_json::json!({
+                            "a": ends[0],
+                            "b": ends[1],
+                            "synapse": synapse,
+                        })),
+                        _ => Err(PersistError::Corrupt("clé de synapse v0 invalide".into())),
+                    }
+                }
+                _ => Err(PersistError::Corrupt(
+                    "entrée de synapse v0 invalide".into(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(Value::Object(map)) if map.is_empty() => vec![],
+        Some(_) => {
+            return Err(PersistError::Corrupt(
+                "synapses v0 dans un format inconnu".into(),
+            ));
+        }
+    };
+
+    obj.insert("synapses".into(), Value::Array(edges));
+    obj.insert("version".into(), Value::from(1));
+    Ok(doc)
+}
+
+// v1 : synapses symétriques `{a, b}` (a <= b). v2 : synapses orientées
+// `{pre, post}` ; chaque ancienne arête devient deux arêtes de même force
+// pour ne rien perdre de ce qui avait été appris.
+fn migrate_v1_to_v2(mut doc: Value) -> Result<Value, PersistError> {
+    let obj = doc
+        .as_object_mut()
+        .ok_or_else(|| PersistError::Corrupt("le document n'est pas un objet".into()))?;
+
+    let edges = match obj.remove("synapses") {
+        Some(Value::Array(edges)) => edges,
+        None => vec![],
+        Some(_) => return Err(PersistError::Corrupt("synapses v1 invalides".into())),
+    };
+
+    let mut directed: BTreeMap<(String, String), Value> = BTreeMap::new();
+    for edge in edges {
+        // Les snapshots binaires v1 arrivent déjà avec les noms pre/post
+        let end = |new: &str, old: &str| {
+            edge.get(new)
+                .or_else(|| edge.get(old))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| PersistError::Corrupt("extrémité de synapse v1 invalide".into()))
+        };
+        let a = end("pre", "a")?;
+        let b = end("post", "b")?;
+        let synapse = edge.get("synapse").cloned().unwrap_or_default();
+
+        directed.insert((a.clone(), b.clone()), synapse.clone());
+        directed.entry((b, a)).or_insert(synapse);
+    }
+
+    let edges = directed
+        .into_iter()
+        .map(|((pre, post), synapse)| {
+            serde_json::json!({ "pre": pre, "post": post, "synapse": synapse })
+        })
+        .collect();
+    obj.insert("synapses".into(), Value::Array(edges));
+    obj.insert("version".into(), Value::from(2));
+    Ok(doc)
+}
+
+// v2 : neurones en objet `{mot: neurone}`, donc triés par mot. v3 : liste
+// `[{word, neuron}]` dans l'ordre du vocabulaire ; l'ordre alphabétique des
+// anciens fichiers devient celui des identifiants.
+fn migrate_v2_to_v3(mut doc: Value) -> Result<Value, PersistError> {
+    let obj = doc
+        .as_object_mut()
+        .ok_or_else(|| PersistError::Corrupt("le document n'est pas un objet".into()))?;
+
+    let neurons = match obj.remove("neurons") {
+        Some(Value::Object(map)) => map
+            .into_iter()
+            .map(|(word, neuron)| serde_json::json!({ "word": word, "neuron": neuron }))
+            .collect(),
+        // Les snapshots binaires v2 arrivent déjà en liste
+        Some(Value::Array(entries)) => entries,
+        None => vec![],
+        Some(_) => return Err(PersistError::Corrupt("neurones v2 invalides".into())),
+    };
+    obj.insert("neurons".into(), Value::Array(neurons));
+    obj.insert("version".into(), Value::from(3));
+    Ok(doc)
+}
@@ -0,0 +1,207 @@
+// Une conversation en cours : le cerveau, sa sauvegarde et son journal.
+// Le REPL et la TUI ne font qu'afficher ce que renvoie `Session::handle`.
+
+use crate::cli::Options;
+use crate::fail;
+use ai_baby::Brain;
+use ai_baby::brain::Interaction;
+use ai_baby::logging;
+use ai_baby::persist::Autosave;
+use ai_baby::persist::journal::{Journal, JournalEvent, Settings};
+use log::error;
+
+/// Lignes d'accueil, avant la première entrée.
+pub const GREETING: &[&str] = &[
+    "🍼 Bébé neuronal réveillé.",
+    "Parle-lui. Ctrl+C pour arrêter.",
+    "/good ou /bad pour juger sa dernière réponse, /stade pour son âge, /metrics pour ses compteurs.",
+    "(il apprend tout, même la merde 😈)",
+];
+
+/// Ce qu'a donné une ligne tapée.
+pub enum Turn {
+    /// Réponse à une commande (`/stade`, `/save`…), sans apprentissage.
+    Note(String),
+    /// Un tour de conversation complet.
+    Interaction(Interaction),
+}
+
+impl Turn {
+    /// Ce que le bébé affiche en retour.
+    pub fn reply(&self) -> &str {
+        match self {
+            Turn::Note(note) => note,
+            Turn::Interaction(interaction) => &interaction.reply,
+        }
+    }
+}
+
+pub struct Session<'a> {
+    options: &'a Options,
+    autosave: Autosave,
+    journal: Option<Journal>,
+    /// Dernière sauvegarde ratée : le fichier est en retard sur le journal.
+    unsaved: bool,
+    pub brain: Brain,
+}
+
+impl<'a> Session<'a> {
+    /// Recharge le cerveau sauvegardé (ou en crée un neuf) et ouvre le
+    /// journal. Un cerveau illisible n'est jamais remplacé en silence.
+    pub fn open(options: &'a Options) -> Self {
+        let autosave = options.autosave();
+        // Réglages du cerveau rechargé, avant ceux de la ligne de commande
+        let (brain, loaded, fallback) = match autosave.recover() {
+            Ok((mut brain, backup)) => {
+                let loaded = Settings::of(&brain);
+                // Une sauvegarde de secours est en retard sur le journal
+                let fallback = backup.and_then(|_| resume_event(&brain));
+                options.configure(&mut brain);
+                (brain, Some(loaded), fallback)
+            }
+            Err(e) if e.is_not_found() => (options.new_brain(), None, None),
+            Err(e) => {
+                eprintln!("(déplace ou répare le fichier avant de relancer)");
+                fail(
+                    &format!("Impossible de charger {}", autosave.path.display()),
+                    e,
+                );
+            }
+        };
+        let journal = options.journal_path.as_ref().map(|path| {
+            Journal::open(path).unwrap_or_else(|e| fail("Journal d'expérience inaccessible", e))
+        });
+        let mut session = Self {
+            options,
+            autosave,
+            journal,
+            unsaved: false,
+            brain,
+        };
+        session.resume_journal(loaded, fallback);
+        session
+    }
+
+    // Le journal doit suffire à reconstruire le cerveau : snapshot de départ
+    // s'il commence, sinon ce qui a changé depuis sa dernière entrée
+    fn resume_journal(&mut self, loaded: Option<Settings>, fallback: Option<JournalEvent>) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+        if journal.is_empty() {
+            if let Err(e) = journal.write_seed(&self.brain) {
+                fail("Snapshot de départ du journal impossible", e);
+            }
+            return;
+        }
+        if let Some(resume) = fallback {
+            self.record(resume);
+        }
+        let settings = Settings::of(&self.brain);
+        match loaded {
+            None => self.record(self.reset_event()),
+            Some(loaded) if loaded != settings => self.record(JournalEvent::Configure(settings)),
+            Some(_) => {}
+        }
+    }
+
+    fn reset_event(&self) -> JournalEvent {
+        JournalEvent::Reset {
+            seed: Some(self.brain.seed),
+            settings: Some(Settings::of(&self.brain)),
+        }
+    }
+
+    /// Traite une ligne non vide : commande ou tour de conversation.
+    pub fn handle(&mut self, input: &str) -> Turn {
+        match input {
+            "/reset" => {
+                // Journalisé seulement une fois le cerveau neuf sur disque
+                let old = std::mem::replace(&mut self.brain, self.options.new_brain());
+                if !self.save() {
+                    self.brain = old;
+                    return Turn::Note("(reset impossible : sauvegarde ratée.)".to_string());
+                }
+                self.record(self.reset_event());
+                Turn::Note("… (reset total)".to_string())
+            }
+            "/stade" => Turn::Note(format!(
+                "(stade : {} — {} tours, {} spikes, {} mots)",
+                self.brain.stage(),
+                self.brain.age.turns,
+                self.brain.age.spikes,
+                self.brain.vocab.len()
+            )),
+            "/metrics" => {
+                let c = &self.brain.metrics.counters;
+                Turn::Note(format!(
+                    "(ticks : {}, spikes : {}, apprentissages : {}, neurones créés : {}, synapses créées : {})",
+                    c.ticks, c.spikes, c.learning_steps, c.neurons_created, c.synapses_created
+                ))
+            }
+            "/save" => {
+                if self.save() {
+                    Turn::Note("(je me suis sauvegardé.)".to_string())
+                } else {
+                    Turn::Note("(sauvegarde impossible.)".to_string())
+                }
+            }
+            _ => {
+                // -------- écoute, apprentissage, réponse --------
+                let interaction = self.brain.experience(input);
+
+                // -------- autosave léger, puis journal --------
+                self.save();
+                self.record(JournalEvent::Interaction(interaction.clone()));
+                Turn::Interaction(interaction)
+            }
+        }
+    }
+
+    // Une sauvegarde ratée laisse le fichier en retard sur le journal
+    // jusqu'à la prochaine réussie
+    fn save(&mut self) -> bool {
+        match self.autosave.save(&self.brain) {
+            Ok(()) => self.unsaved = false,
+            Err(e) => {
+                error!(target: logging::PERSIST, "Échec de la sauvegarde: {}", e);
+                self.unsaved = true;
+            }
+        }
+        !self.unsaved
+    }
+
+    fn record(&mut self, event: JournalEvent) {
+        if let Some(journal) = self.journal.as_mut()
+            && let Err(e) = journal.append(event)
+        {
+            error!(target: logging::PERSIST, "Échec d'écriture du journal: {}", e);
+        }
+    }
+}
+
+fn resume_event(brain: &Brain) -> Option<JournalEvent> {
+    JournalEvent::resume(brain)
+        .map_err(|e| error!(target: logging::PERSIST, "Reprise non journalisée: {}", e))
+        .ok()
+}
+
+impl Drop for Session<'_> {
+    // Fin de session sur une sauvegarde ratée : dernier essai, sinon le
+    // journal note le cerveau que la prochaine session rechargera
+    fn drop(&mut self) {
+        if !self.unsaved || self.journal.is_none() || self.save() {
+            return;
+        }
+        match self.autosave.load() {
+            Ok(brain) => {
+                if let Some(resume) = resume_event(&brain) {
+                    self.record(resume);
+                }
+            }
+            // Rien sur disque : la prochaine session journalisera son cerveau neuf
+            Err(e) if e.is_not_found() => {}
+            Err(e) => error!(target: logging::PERSIST, "Cerveau sauvegardé illisible: {}", e),
+        }
+    }
+}
@@ -1,10 +1,15 @@
 use crate::neurotransmitter::Neurotransmitter;
 use serde::{Deserialize, Serialize};
 
+/// Connexion entre deux neurones-mots.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Synapse {
     pub strength: f32,                      // Force de la connexion
     pub neurotransmitter: Neurotransmitter, // Type de neurotransmetteur
+    /// Trace d'éligibilité, dans [-1, 1] : ce que la dopamine convertira en
+    /// changement de poids.
+    #[serde(default)]
+    pub eligibility: f32,
 }
 
 impl Synapse {
@@ -12,9 +17,14 @@ impl Synapse {
         Self {
             strength,
             neurotransmitter,
+            eligibility: 0.0,
         }
     }
 
+    pub fn add_eligibility(&mut self, amount: f32) {
+        self.eligibility = (self.eligibility + amount).clamp(-1.0, 1.0);
+    }
+
     pub fn is_excitatory(&self) -> bool {
         matches!(self.neurotransmitter, Neurotransmitter::Glutamate)
     }
@@ -22,4 +32,9 @@ impl Synapse {
     pub fn is_inhibitory(&self) -> bool {
         matches!(self.neurotransmitter, Neurotransmitter::Gaba)
     }
+
+    /// Ne transmet pas de courant : module l'apprentissage de sa cible.
+    pub fn is_dopaminergic(&self) -> bool {
+        matches!(self.neurotransmitter, Neurotransmitter::Dopamine)
+    }
 }
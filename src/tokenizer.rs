@@ -1,11 +1,253 @@
+// Découpage du texte en unités, chacune deviendra un neurone.
+//
+// Par défaut, un neurone par mot. Les découpages en syllabes ou en n-grammes
+// de caractères donnent au bébé des briques plus petites : il peut les
+// recombiner en mots nouveaux, et un mot mal écrit (« mamaaan ») partage ses
+// briques avec le mot connu (« maman »).
+
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use unicode_normalization::char::is_combining_mark;
+
 pub type Word = String;
 
-// Tokenisation volontairement simple et marrante.
+/// Tokenisation volontairement simple et marrante. Les accents décomposés
+/// (voir [`crate::normalize`]) restent attachés à leur lettre.
 pub fn tokenize(text: &str) -> Vec<Word> {
     let lowered = text.to_lowercase();
     lowered
-        .split(|c: char| !c.is_alphanumeric() && c != '\'' && c != '_')
+        .split(|c: char| !c.is_alphanumeric() && c != '\'' && c != '_' && !is_combining_mark(c))
         .filter(|w| !w.trim().is_empty())
         .map(|w| w.to_string())
         .collect()
 }
+
+/// Façon de découper le texte en unités.
+pub trait Tokenizer {
+    /// Les unités de `text`, dans l'ordre.
+    fn tokenize(&self, text: &str) -> Vec<Word>;
+
+    /// Recolle une suite d'unités en texte.
+    fn detokenize(&self, tokens: &[&str]) -> String {
+        tokens.join(" ")
+    }
+}
+
+/// Un mot, une unité : le découpage historique ([`tokenize`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WordTokenizer;
+
+impl Tokenizer for WordTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Word> {
+        tokenize(text)
+    }
+}
+
+/// Syllabes à la française. Les syllabes dites s'enchaînent en un seul mot :
+/// le bébé invente ainsi des mots à partir de morceaux connus.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyllableTokenizer;
+
+impl Tokenizer for SyllableTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Word> {
+        tokenize(text)
+            .iter()
+            .flat_map(|w| w.split('\''))
+            .filter(|w| !w.is_empty())
+            .flat_map(syllabify)
+            .collect()
+    }
+
+    fn detokenize(&self, tokens: &[&str]) -> String {
+        tokens.concat()
+    }
+}
+
+/// N-grammes de caractères de chaque mot, bordé de `_` : « chat » en
+/// trigrammes donne `_ch`, `cha`, `hat`, `at_`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NgramTokenizer {
+    pub n: usize,
+}
+
+impl Default for NgramTokenizer {
+    fn default() -> Self {
+        Self { n: 3 }
+    }
+}
+
+impl Tokenizer for NgramTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Word> {
+        let n = self.n.max(1);
+        let mut grams = vec![];
+        for w in tokenize(text) {
+            let chars: Vec<char> = format!("_{}_", w).chars().collect();
+            if chars.len() <= n {
+                grams.push(chars.iter().collect());
+                continue;
+            }
+            grams.extend(chars.windows(n).map(|g| g.iter().collect::<Word>()));
+        }
+        grams
+    }
+
+    // Les n-grammes qui se chevauchent se recollent : `_ch`, `cha`, `hat`,
+    // `at_` redonnent « chat »
+    fn detokenize(&self, tokens: &[&str]) -> String {
+        let mut text = String::new();
+        for gram in tokens {
+            let overlap: String = gram.chars().take(self.n.max(1) - 1).collect();
+            match gram.chars().last() {
+                Some(last)
+                    if !text.is_empty() && !overlap.is_empty() && text.ends_with(&overlap) =>
+                {
+                    text.push(last)
+                }
+                _ => {
+                    text.push(' ');
+                    text.push_str(gram);
+                }
+            }
+        }
+        text.replace('_', " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+const VOWELS: &str = "aeiouyàâäéèêëîïôöùûüœæ";
+
+// Consonne suivie de l/r qu'on ne sépare pas : « ta-ble », « pa-trie »
+fn inseparable(a: char, b: char) -> bool {
+    let digraph = matches!((a, b), ('c', 'h') | ('p', 'h') | ('t', 'h') | ('g', 'n'));
+    let liquid = "bcdfgkptv".contains(a) && (b == 'l' || b == 'r');
+    digraph || liquid
+}
+
+/// Découpe un mot en syllabes avec une heuristique pour le français :
+/// - les voyelles qui se suivent forment un même noyau (« ou », « eau ») ;
+/// - une consonne seule entre deux noyaux ouvre la syllabe suivante
+///   (« ma-man ») ;
+/// - deux consonnes se séparent (« don-ne »), sauf consonne + l/r et ch, ph,
+///   th, gn (« ta-ble », « ro-cher ») ;
+/// - le « u » de « qu » et « gu » devant une voyelle compte comme consonne.
+///
+/// Les lettres répétées pour insister sont d'abord ramenées à une seule :
+/// « mamaaan » se découpe comme « maman ».
+pub fn syllabify(word: &str) -> Vec<Word> {
+    let chars = squeeze(word);
+    let is_vowel = |i: usize| {
+        let c = chars[i];
+        let glide_u = c == 'u'
+            && i > 0
+            && matches!(chars[i - 1], 'q' | 'g')
+            && chars.get(i + 1).is_some_and(|&n| VOWELS.contains(n));
+        VOWELS.contains(c) && !glide_u
+    };
+
+    // Noyaux vocaliques : (début, fin exclue)
+    let mut nuclei: Vec<(usize, usize)> = vec![];
+    for i in 0..chars.len() {
+        if !is_vowel(i) {
+            continue;
+        }
+        match nuclei.last_mut() {
+            Some((_, end)) if *end == i => *end = i + 1,
+            _ => nuclei.push((i, i + 1)),
+        }
+    }
+    if nuclei.len() < 2 {
+        return vec![chars.iter().collect()];
+    }
+
+    // Frontière entre deux noyaux, selon les consonnes qui les séparent
+    let mut cuts = vec![];
+    for pair in nuclei.windows(2) {
+        let (from, to) = (pair[0].1, pair[1].0);
+        let cut = match to - from {
+            0 | 1 => from,
+            _ if inseparable(chars[to - 2], chars[to - 1]) => to - 2,
+            _ => to - 1,
+        };
+        cuts.push(cut);
+    }
+
+    let mut syllables = vec![];
+    let mut start = 0;
+    for cut in cuts.into_iter().chain([chars.len()]) {
+        syllables.push(chars[start..cut].iter().collect());
+        start = cut;
+    }
+    syllables
+}
+
+// Une voyelle répétée, ou plus de deux consonnes identiques d'affilée, se
+// réduisent à une seule lettre (« donne » garde ses deux n)
+fn squeeze(word: &str) -> Vec<char> {
+    let mut chars = vec![];
+    let mut letters = word.chars().peekable();
+    while let Some(c) = letters.next() {
+        let mut run = 1;
+        while letters.next_if_eq(&c).is_some() {
+            run += 1;
+        }
+        let keep = if VOWELS.contains(c) || run > 2 {
+            1
+        } else {
+            run
+        };
+        chars.extend(std::iter::repeat_n(c, keep));
+    }
+    chars
+}
+
+/// Choix du découpage, pour tout le cerveau ([`crate::Brain::tokenizer`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "tokenizer", rename_all = "snake_case")]
+pub enum TokenizerKind {
+    #[default]
+    Word,
+    Syllable,
+    Ngram(NgramTokenizer),
+}
+
+/// Découpage désigné par son nom court : `word`, `syllable`, `ngram` (des
+/// trigrammes) ou `ngramN`.
+impl FromStr for TokenizerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "word" => Ok(Self::Word),
+            "syllable" => Ok(Self::Syllable),
+            "ngram" => Ok(Self::Ngram(NgramTokenizer::default())),
+            other => other
+                .strip_prefix("ngram")
+                .and_then(|n| n.parse().ok())
+                .filter(|&n| n > 0)
+                .map(|n| Self::Ngram(NgramTokenizer { n }))
+                .ok_or_else(|| format!("tokenizer inconnu: {}", other)),
+        }
+    }
+}
+
+impl TokenizerKind {
+    fn get(&self) -> &dyn Tokenizer {
+        match self {
+            Self::Word => &WordTokenizer,
+            Self::Syllable => &SyllableTokenizer,
+            Self::Ngram(t) => t,
+        }
+    }
+}
+
+impl Tokenizer for TokenizerKind {
+    fn tokenize(&self, text: &str) -> Vec<Word> {
+        self.get().tokenize(text)
+    }
+
+    fn detokenize(&self, tokens: &[&str]) -> String {
+        self.get().detokenize(tokens)
+    }
+}
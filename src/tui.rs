@@ -0,0 +1,303 @@
+// Tableau de bord plein écran (`--tui`, fonctionnalité `tui`).
+//
+// À gauche la conversation et la ligne de saisie ; à droite la chimie du
+// bébé en jauges, un raster des derniers spikes (un mot par ligne, un tick
+// par colonne), suivis tick par tick pendant la réflexion, et les synapses les plus fortes parmi celles que le dernier
+// tour a mises en jeu, avec leur variation pendant ce tour.
+
+use crate::session::{GREETING, Session, Turn};
+use ai_baby::brain::{SynapseKey, TouchedSynapse};
+use ai_baby::{Modulator, Word};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Gauge, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::{BTreeMap, VecDeque};
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+// Ticks gardés pour le raster
+const RASTER_TICKS: usize = 200;
+// Synapses affichées
+const TOUCHED: usize = 12;
+// Rafraîchissement pendant que le bébé réfléchit
+const REFRESH: Duration = Duration::from_millis(30);
+
+#[derive(Default)]
+struct App {
+    conversation: Vec<(&'static str, String)>,
+    input: String,
+    modulator: Modulator,
+    raster: VecDeque<Vec<Word>>,
+    touched: Vec<TouchedSynapse>,
+    thinking: bool,
+}
+
+impl App {
+    fn push_tick(&mut self, fired: Vec<Word>) {
+        self.raster.push_back(fired);
+        while self.raster.len() > RASTER_TICKS {
+            self.raster.pop_front();
+        }
+    }
+}
+
+/// Lance le tableau de bord jusqu'à Échap ou Ctrl+C.
+pub fn run(session: &mut Session) -> io::Result<()> {
+    let mut terminal = ratatui::try_init()?;
+    let result = event_loop(&mut terminal, session);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, session: &mut Session) -> io::Result<()> {
+    let mut app = App {
+        modulator: session.brain.modulator.clone(),
+        ..App::default()
+    };
+    app.conversation
+        .extend(GREETING.iter().map(|line| ("", line.to_string())));
+
+    loop {
+        terminal.draw(|frame| draw(frame, &app))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Backspace => {
+                app.input.pop();
+            }
+            KeyCode::Char(c) => app.input.push(c),
+            KeyCode::Enter => {
+                let input = app.input.trim().to_string();
+                app.input.clear();
+                if input.is_empty() {
+                    continue;
+                }
+                app.conversation.push(("Toi", input.clone()));
+
+                let before: BTreeMap<SynapseKey, f32> = session
+                    .brain
+                    .synapses
+                    .iter()
+                    .map(|(&key, syn)| (key, syn.strength))
+                    .collect();
+                let turn = think(terminal, &mut app, session, &input)?;
+                app.conversation.push(("Lui", turn.reply().to_string()));
+                app.modulator = session.brain.modulator.clone();
+                match &turn {
+                    Turn::Interaction(interaction) => {
+                        app.touched = session
+                            .brain
+                            .touched_synapses(&before, interaction, TOUCHED);
+                    }
+                    Turn::Note(_) if input == "/reset" => {
+                        app.raster.clear();
+                        app.touched.clear();
+                    }
+                    Turn::Note(_) => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Le tour tourne à côté de l'interface : chaque tick arrive par un canal et
+// le tableau se redessine pendant que le bébé réfléchit. On peut continuer à
+// taper la phrase suivante.
+fn think(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    session: &mut Session,
+    input: &str,
+) -> io::Result<Turn> {
+    let (sender, ticks) = mpsc::channel();
+    session.brain.metrics.on_tick = Some(Box::new(move |fired: &[&str], modulator: &Modulator| {
+        let fired = fired.iter().map(|w| w.to_string()).collect();
+        let _ = sender.send((fired, modulator.clone()));
+    }));
+
+    app.thinking = true;
+    let turn = thread::scope(|scope| {
+        let worker = scope.spawn(|| session.handle(input));
+        loop {
+            for (fired, modulator) in ticks.try_iter() {
+                app.push_tick(fired);
+                app.modulator = modulator;
+            }
+            terminal.draw(|frame| draw(frame, app))?;
+            if worker.is_finished() {
+                break;
+            }
+            if event::poll(REFRESH)?
+                && let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                match key.code {
+                    KeyCode::Backspace => {
+                        app.input.pop();
+                    }
+                    KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app.input.push(c)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        // Les derniers ticks, envoyés juste avant la fin du tour
+        for (fired, _) in ticks.try_iter() {
+            app.push_tick(fired);
+        }
+        Ok(worker
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+    });
+    app.thinking = false;
+    session.brain.metrics.on_tick = None;
+    turn
+}
+
+fn draw(frame: &mut Frame, app: &App) {
+    let [left, right] =
+        Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .areas(frame.area());
+    let [conversation, input] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).areas(left);
+    let [gauges, raster, synapses] = Layout::vertical([
+        Constraint::Length(7),
+        Constraint::Min(6),
+        Constraint::Length(TOUCHED as u16 + 2),
+    ])
+    .areas(right);
+
+    draw_conversation(frame, app, conversation);
+    draw_input(frame, app, input);
+    draw_gauges(frame, &app.modulator, gauges);
+    draw_raster(frame, app, raster);
+    draw_touched(frame, app, synapses);
+}
+
+fn draw_conversation(frame: &mut Frame, app: &App, area: Rect) {
+    let height = area.height.saturating_sub(2) as usize;
+    let skip = app.conversation.len().saturating_sub(height);
+    let lines: Vec<Line> = app.conversation[skip..]
+        .iter()
+        .map(|(who, text)| match *who {
+            "" => Line::from(text.as_str()).dark_gray(),
+            "Toi" => Line::from(vec![Span::from("Toi > ").cyan(), Span::from(text.as_str())]),
+            _ => Line::from(vec![
+                Span::from("Lui > ").magenta(),
+                Span::from(text.as_str()),
+            ]),
+        })
+        .collect();
+    let block = Block::bordered().title(" Conversation ");
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_input(frame: &mut Frame, app: &App, area: Rect) {
+    let title = if app.thinking {
+        " Il réfléchit… "
+    } else {
+        " Échap pour quitter "
+    };
+    let prompt = format!("Toi > {}", app.input);
+    let width = prompt.chars().count() as u16;
+    frame.render_widget(
+        Paragraph::new(prompt).block(Block::bordered().title(title)),
+        area,
+    );
+    frame.set_cursor_position((area.x + 1 + width, area.y + 1));
+}
+
+fn draw_gauges(frame: &mut Frame, m: &Modulator, area: Rect) {
+    let block = Block::bordered().title(" Chimie ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let levels = [
+        ("dopamine", m.dopamine, Color::Yellow),
+        ("stress", m.stress, Color::Red),
+        ("sérotonine", m.serotonin, Color::Blue),
+        ("noradrénaline", m.noradrenaline, Color::Magenta),
+        ("endorphines", m.endorphins, Color::Green),
+    ];
+    let rows = Layout::vertical([Constraint::Length(1); 5]).split(inner);
+    for ((name, level, color), row) in levels.into_iter().zip(rows.iter()) {
+        let gauge = Gauge::default()
+            .gauge_style(Style::new().fg(color))
+            .ratio(level.clamp(0.0, 1.0) as f64)
+            .label(format!("{} {:.2}", name, level));
+        frame.render_widget(gauge, *row);
+    }
+}
+
+fn draw_raster(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::bordered().title(" Spikes ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Mots qui ont spiké dans la fenêtre, les plus récents d'abord
+    let label = 12;
+    let ticks = (inner.width as usize).saturating_sub(label + 1);
+    let window: Vec<&Vec<Word>> = app.raster.iter().rev().take(ticks).rev().collect();
+    let mut words: Vec<&Word> = vec![];
+    for fired in window.iter().rev() {
+        for w in fired.iter() {
+            if !words.contains(&w) {
+                words.push(w);
+            }
+        }
+    }
+    words.truncate(inner.height as usize);
+
+    let lines: Vec<Line> = words
+        .iter()
+        .map(|word| {
+            let name: String = word.chars().take(label).collect();
+            let dots: String = window
+                .iter()
+                .map(|fired| if fired.contains(word) { '█' } else { '·' })
+                .collect();
+            Line::from(vec![
+                Span::from(format!("{:>label$} ", name)),
+                Span::from(dots).yellow(),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_touched(frame: &mut Frame, app: &App, area: Rect) {
+    let lines: Vec<Line> = app
+        .touched
+        .iter()
+        .map(|t| {
+            let arrow = if t.inhibitory { " ⊣ " } else { " → " };
+            let delta = format!(" ({:+.3})", t.delta);
+            let delta = if t.delta >= 0.0 {
+                Span::from(delta).green()
+            } else {
+                Span::from(delta).red()
+            };
+            Line::from(vec![
+                Span::from(format!("{}{}{}", t.pre, arrow, t.post)),
+                Span::from(format!("  {:.3}", t.strength)).bold(),
+                delta,
+            ])
+        })
+        .collect();
+    let block = Block::bordered().title(" Synapses du dernier tour ");
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use ai_baby::Brain;
+use ai_baby::persist::Autosave;
+use common::{snapshot_json, temp_path};
+use std::fs;
+
+fn cleanup(autosave: &Autosave) {
+    let _ = fs::remove_file(&autosave.path);
+    for n in 1..=autosave.backups + 1 {
+        let _ = fs::remove_file(autosave.backup_path(n));
+    }
+}
+
+// Sauvegarde un cerveau après chaque phrase et renvoie les états successifs
+fn save_each(autosave: &Autosave, lines: &[&str]) -> Vec<String> {
+    let mut brain = Brain::with_seed(9);
+    lines
+        .iter()
+        .map(|line| {
+            brain.interact(line);
+            autosave.save(&brain).unwrap();
+            snapshot_json(&brain)
+        })
+        .collect()
+}
+
+fn saved(path: &std::path::Path) -> String {
+    let brain = ai_baby::persist::load(path).unwrap();
+    snapshot_json(&brain)
+}
+
+#[test]
+fn backups_rotate_newest_first() {
+    let autosave = Autosave::new(temp_path("rotate.json")).with_backups(3);
+    let states = save_each(&autosave, &["bonjour", "le chat", "dort", "maman"]);
+
+    assert_eq!(saved(&autosave.path), states[3]);
+    assert_eq!(saved(&autosave.backup_path(1)), states[2]);
+    assert_eq!(saved(&autosave.backup_path(2)), states[1]);
+    assert_eq!(saved(&autosave.backup_path(3)), states[0]);
+    cleanup(&autosave);
+}
+
+#[test]
+fn backups_are_capped() {
+    let autosave = Autosave::new(temp_path("cap.json")).with_backups(2);
+    let states = save_each(&autosave, &["un", "deux", "trois", "quatre", "cinq"]);
+
+    assert_eq!(saved(&autosave.backup_path(1)), states[3]);
+    assert_eq!(saved(&autosave.backup_path(2)), states[2]);
+    assert!(!autosave.backup_path(3).exists());
+    cleanup(&autosave);
+}
+
+#[test]
+fn corrupt_primary_falls_back_to_latest_backup() {
+    let autosave = Autosave::new(temp_path("fallback.json")).with_backups(2);
+    let states = save_each(&autosave, &["bonjour", "le chat"]);
+    fs::write(&autosave.path, b"{ tronqu").unwrap();
+
+    let brain = autosave.load().unwrap();
+    assert_eq!(snapshot_json(&brain), states[0]);
+    cleanup(&autosave);
+}
@@ -0,0 +1,137 @@
+// Outils partagés par les tests d'intégration (et le bench `tick`).
+#![allow(dead_code)]
+
+use ai_baby::brain::{ensure_neuron, ensure_synapse};
+use ai_baby::persist::BrainSnapshot;
+use ai_baby::{Brain, Composition, NeuronId, Neurotransmitter, SimulationMode, SynapseKey};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::path::PathBuf;
+
+/// Chemin temporaire propre à ce processus et à ce nom.
+pub fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("ai_baby_{}_{}", std::process::id(), name))
+}
+
+/// Snapshot JSON du cerveau, comparable octet à octet.
+pub fn snapshot_json(brain: &Brain) -> String {
+    serde_json::to_string(&BrainSnapshot::from(brain)).unwrap()
+}
+
+/// Force de la synapse `pre` → `post`, 0 si elle n'existe pas.
+pub fn strength(brain: &Brain, pre: &str, post: &str) -> f32 {
+    let key = SynapseKey::new(brain.vocab.id(pre).unwrap(), brain.vocab.id(post).unwrap());
+    brain.synapses.get(&key).map_or(0.0, |syn| syn.strength)
+}
+
+/// Mot du `i`-ième neurone de [`network`].
+pub fn word(i: usize) -> String {
+    format!("mot{}", i)
+}
+
+/// Réseau aléatoire reproductible : `size` mots, `fan_out` synapses
+/// sortantes chacun (une sur cinq inhibitrice), potentiels de départ tirés
+/// entre 0 et 1.2.
+pub fn network(seed: u64, simulation: SimulationMode, size: usize, fan_out: usize) -> Brain {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut brain = Brain::with_seed(seed);
+    brain.simulation = simulation;
+
+    let ids: Vec<NeuronId> = (0..size)
+        .map(|i| {
+            ensure_neuron(
+                &mut brain.vocab,
+                &mut brain.neurons,
+                &word(i),
+                Composition::default(),
+            )
+        })
+        .collect();
+    for &pre in &ids {
+        for _ in 0..fan_out {
+            let post = ids[rng.gen_range(0..size)];
+            let neurotransmitter = if rng.gen_bool(0.2) {
+                Neurotransmitter::Gaba
+            } else {
+                Neurotransmitter::Glutamate
+            };
+            let syn = ensure_synapse(&mut brain.synapses, pre, post, neurotransmitter);
+            syn.strength = rng.gen_range(0.2..1.4);
+        }
+    }
+    for n in brain.neurons.iter_mut() {
+        n.v = rng.gen_range(0.0..1.2);
+    }
+    brain
+}
+
+/// Neurone du mot `w`, créé si besoin.
+pub fn neuron(brain: &mut Brain, w: &str) -> NeuronId {
+    ensure_neuron(
+        &mut brain.vocab,
+        &mut brain.neurons,
+        w,
+        Composition::default(),
+    )
+}
+
+/// Un bébé qui vient de répondre "miaou" à "chat" (en babillant). "chien" → "miaou" existe
+/// aussi mais n'a rien produit.
+pub fn replied() -> Brain {
+    let mut brain = Brain::with_seed(17);
+    let [chat, chien, miaou] = ["chat", "chien", "miaou"].map(|w| neuron(&mut brain, w));
+    ensure_synapse(
+        &mut brain.synapses,
+        chat,
+        miaou,
+        Neurotransmitter::Glutamate,
+    )
+    .strength = 1.0;
+    ensure_synapse(
+        &mut brain.synapses,
+        chien,
+        miaou,
+        Neurotransmitter::Glutamate,
+    )
+    .strength = 1.0;
+    // Assez excité pour répondre sans spiker
+    brain.neurons[chat.index()].threshold = 3.0;
+    brain.neurons[chat.index()].v = 1.0;
+    // Il babille encore, mais c'est bien "miaou" qu'il voulait dire
+    assert_eq!(brain.interact("chat"), "miaoumiaou");
+    assert_eq!(brain.last_reply_words, ["miaou"]);
+    brain
+}
+
+/// Synapse excitatrice `pre` → `post` de cette force, neurones créés si
+/// besoin.
+pub fn connect(brain: &mut Brain, pre: &str, post: &str, strength: f32) {
+    connect_with(brain, pre, post, Neurotransmitter::Glutamate, strength);
+}
+
+/// Comme [`connect`], avec ce neurotransmetteur.
+pub fn connect_with(
+    brain: &mut Brain,
+    pre: &str,
+    post: &str,
+    neurotransmitter: Neurotransmitter,
+    strength: f32,
+) {
+    let [pre, post] = [pre, post].map(|w| neuron(brain, w));
+    ensure_synapse(&mut brain.synapses, pre, post, neurotransmitter).strength = strength;
+}
+
+/// Un seul mot-graine.
+pub fn seed(w: &str) -> Vec<String> {
+    vec![w.to_string()]
+}
+
+/// Une phrase déjà apprise : "maman donne le biberon", réponses gloutonnes.
+pub fn sentence() -> Brain {
+    let mut brain = Brain::with_seed(18);
+    connect(&mut brain, "maman", "donne", 1.0);
+    connect(&mut brain, "donne", "le", 1.0);
+    connect(&mut brain, "le", "biberon", 1.0);
+    brain.modulator.noradrenaline = 1.0;
+    brain
+}
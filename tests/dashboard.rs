@@ -0,0 +1,70 @@
+mod common;
+
+use ai_baby::Brain;
+use ai_baby::brain::SynapseKey;
+use common::{connect, strength};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+fn strengths(brain: &Brain) -> BTreeMap<SynapseKey, f32> {
+    brain
+        .synapses
+        .iter()
+        .map(|(&key, syn)| (key, syn.strength))
+        .collect()
+}
+
+#[test]
+fn touched_synapses_are_the_strongest_of_the_turn() {
+    let mut brain = Brain::with_seed(1);
+    connect(&mut brain, "loin", "ailleurs", 2.9);
+    let before = strengths(&brain);
+    let interaction = brain.experience("maman chaton");
+
+    let touched = brain.touched_synapses(&before, &interaction, 5);
+    assert_eq!(touched.len(), 5);
+    assert!(
+        touched
+            .windows(2)
+            .all(|pair| pair[0].strength >= pair[1].strength)
+    );
+    for t in &touched {
+        // Hors du tour, même plus forte
+        assert_ne!(t.pre, "loin");
+        assert_eq!(t.strength, strength(&brain, &t.pre, &t.post));
+        let key = SynapseKey::new(
+            brain.vocab.id(&t.pre).unwrap(),
+            brain.vocab.id(&t.post).unwrap(),
+        );
+        let old = before.get(&key).copied().unwrap_or(0.0);
+        assert_eq!(t.delta, t.strength - old);
+    }
+
+    // Une synapse née pendant le tour varie de toute sa force
+    let all = brain.touched_synapses(&before, &interaction, usize::MAX);
+    let born = all.iter().find(|t| t.pre == "maman" && t.post == "chaton");
+    let born = born.expect("maman → chaton mise en jeu");
+    assert_eq!(born.delta, born.strength);
+}
+
+#[test]
+fn the_tick_observer_sees_every_spike_of_the_turn() {
+    let mut brain = Brain::with_seed(1);
+    let seen = Arc::new(Mutex::new(vec![]));
+    let sink = Arc::clone(&seen);
+    brain.metrics.on_tick = Some(Box::new(move |fired, _| {
+        let mut fired: Vec<String> = fired.iter().map(|w| w.to_string()).collect();
+        fired.sort();
+        sink.lock().unwrap().push(fired);
+    }));
+
+    let interaction = brain.experience("maman amour");
+
+    let expected: Vec<Vec<String>> = interaction
+        .spikes
+        .iter()
+        .chain(&interaction.reply_spikes)
+        .cloned()
+        .collect();
+    assert_eq!(*seen.lock().unwrap(), expected);
+}
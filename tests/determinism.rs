@@ -0,0 +1,37 @@
+mod common;
+
+use ai_baby::Brain;
+use ai_baby::persist::BrainSnapshot;
+use common::snapshot_json;
+
+const TRANSCRIPT: &[&str] = &[
+    "bonjour maman",
+    "papa est là, bien !",
+    "j'ai peur du noir",
+    "calme, calme bébé",
+    "maman papa amour joie",
+];
+
+fn raise(seed: u64) -> Brain {
+    let mut brain = Brain::with_seed(seed);
+    for line in TRANSCRIPT {
+        brain.interact(line);
+    }
+    brain
+}
+
+#[test]
+fn same_seed_same_inputs_give_identical_snapshots() {
+    assert_eq!(snapshot_json(&raise(42)), snapshot_json(&raise(42)));
+}
+
+#[test]
+fn reloaded_brain_keeps_living_identically() {
+    let mut original = raise(7);
+    let json = snapshot_json(&original);
+    let mut reloaded: Brain = serde_json::from_str::<BrainSnapshot>(&json).unwrap().into();
+
+    original.interact("encore maman");
+    reloaded.interact("encore maman");
+    assert_eq!(snapshot_json(&original), snapshot_json(&reloaded));
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use ai_baby::brain::{Age, Brain, Milestone, Stage};
+use ai_baby::persist::BrainSnapshot;
+use common::{seed, sentence};
+
+fn is_babble(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    let (a, b) = chars.split_at(chars.len() / 2);
+    !a.is_empty() && a == b
+}
+
+#[test]
+fn a_newborn_babbles_doubled_syllables() {
+    let mut brain = sentence();
+    assert_eq!(brain.stage(), Stage::Babbling);
+    assert_eq!(brain.reply(&seed("maman")), "dodo lele");
+
+    let reply = brain.interact("bonjour toi");
+    assert!(!reply.is_empty());
+    assert!(reply.split_whitespace().all(is_babble), "{}", reply);
+}
+
+#[test]
+fn babbling_learns_what_it_meant_not_what_it_said() {
+    let mut brain = sentence();
+    assert_eq!(brain.interact("maman"), "dodo lele");
+
+    assert_eq!(brain.last_reply_words, ["donne", "le"]);
+    assert!(brain.vocab.id("dodo").is_none());
+    assert!(brain.vocab.id("lele").is_none());
+}
+
+#[test]
+fn reply_length_grows_with_the_stage() {
+    let mut brain = sentence();
+    let milestone = |turns| Milestone {
+        turns,
+        spikes: 0,
+        vocabulary: 0,
+    };
+    brain.development.single_words = milestone(1);
+    brain.development.two_words = milestone(2);
+    brain.development.short_phrases = milestone(3);
+
+    let expected = [
+        (Stage::SingleWords, "donne"),
+        (Stage::TwoWords, "donne le"),
+        (Stage::ShortPhrases, "donne le biberon"),
+    ];
+    for (turns, (stage, reply)) in (1..).zip(expected) {
+        brain.age = Age { turns, spikes: 0 };
+        assert_eq!(brain.stage(), stage);
+        assert_eq!(brain.reply(&seed("maman")), reply);
+    }
+}
+
+#[test]
+fn every_milestone_criterion_is_required() {
+    let mut brain = sentence();
+    brain.development.single_words = Milestone {
+        turns: 3,
+        spikes: 5,
+        vocabulary: 5,
+    };
+    brain.age = Age {
+        turns: 10,
+        spikes: 4,
+    };
+    assert_eq!(brain.stage(), Stage::Babbling);
+    brain.age.spikes = 5;
+    assert_eq!(brain.stage(), Stage::SingleWords);
+}
+
+#[test]
+fn conversation_ages_the_baby_and_stages_never_regress() {
+    let mut brain = Brain::with_seed(19);
+    let mut stages = vec![brain.stage()];
+    for i in 0..60 {
+        brain.interact(&format!("regarde le mot{} et le mot{}", i, i + 1));
+        stages.push(brain.stage());
+    }
+    assert_eq!(brain.age.turns, 60);
+    assert!(stages.windows(2).all(|w| w[0] <= w[1]));
+    assert!(*stages.last().unwrap() > Stage::Babbling, "{:?}", brain.age);
+}
+
+#[test]
+fn age_survives_a_snapshot() {
+    let mut brain = sentence();
+    brain.interact("maman");
+    brain.interact("papa");
+    let reloaded: Brain = BrainSnapshot::from(&brain).into();
+    assert_eq!(reloaded.age.turns, 2);
+    assert_eq!(reloaded.age.spikes, brain.age.spikes);
+    assert_eq!(reloaded.stage(), brain.stage());
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use ai_baby::{Brain, NeuronId, SimulationMode};
+use std::collections::BTreeSet;
+
+const SIZE: usize = 400;
+const FAN_OUT: usize = 6;
+const TICKS: usize = 40;
+
+fn network(seed: u64, simulation: SimulationMode) -> Brain {
+    common::network(seed, simulation, SIZE, FAN_OUT)
+}
+
+fn run(seed: u64, simulation: SimulationMode) -> Vec<BTreeSet<NeuronId>> {
+    network(seed, simulation).run_spiking(&[], TICKS)
+}
+
+#[test]
+fn event_driven_matches_dense_spike_history() {
+    for seed in 0..5 {
+        let dense = run(seed, SimulationMode::Dense);
+        let sparse = run(seed, SimulationMode::EventDriven);
+
+        let total: usize = dense.iter().map(BTreeSet::len).sum();
+        assert!(total > 0, "le réseau de test doit être actif");
+
+        let mismatched: usize = dense
+            .iter()
+            .zip(&sparse)
+            .map(|(d, s)| d.symmetric_difference(s).count())
+            .sum();
+        assert!(
+            mismatched * 100 <= total * 2,
+            "graine {}: {} spikes divergents sur {}",
+            seed,
+            mismatched,
+            total
+        );
+    }
+}
+
+#[test]
+fn event_driven_ends_with_close_potentials() {
+    let mut dense = network(9, SimulationMode::Dense);
+    let mut sparse = network(9, SimulationMode::EventDriven);
+    dense.run_spiking(&[], TICKS);
+    sparse.run_spiking(&[], TICKS);
+
+    for (id, w) in dense.vocab.iter() {
+        let (d, s) = (&dense.neurons[id.index()], &sparse.neurons[id.index()]);
+        assert!((d.v - s.v).abs() < 0.15, "{}: {} vs {}", w, d.v, s.v);
+        assert!((dense.threshold(id) - sparse.threshold(id)).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn event_driven_stays_close_over_successive_runs() {
+    let mut dense = network(4, SimulationMode::Dense);
+    let mut sparse = network(4, SimulationMode::EventDriven);
+    let stimuli: Vec<Vec<String>> = (0..6).map(|i| vec![common::word(i * 7)]).collect();
+
+    let (mut mismatched, mut total) = (0, 0);
+    for words in &stimuli {
+        let d = dense.run_spiking(words, TICKS);
+        let s = sparse.run_spiking(words, TICKS);
+        total += d.iter().map(BTreeSet::len).sum::<usize>();
+        mismatched += d
+            .iter()
+            .zip(&s)
+            .map(|(d, s)| d.symmetric_difference(s).count())
+            .sum::<usize>();
+    }
+    assert!(total > 0, "le réseau de test doit être actif");
+    assert!(
+        mismatched * 100 <= total * 5,
+        "{} spikes divergents sur {}",
+        mismatched,
+        total
+    );
+    for (id, w) in dense.vocab.iter() {
+        assert!(
+            (dense.threshold(id) - sparse.threshold(id)).abs() < 1e-4,
+            "{}",
+            w
+        );
+    }
+}
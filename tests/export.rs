@@ -0,0 +1,134 @@
+mod common;
+
+use ai_baby::Neurotransmitter;
+use ai_baby::brain::Brain;
+use ai_baby::export::{GraphFilter, GraphFormat, export_graph};
+use common::connect_with;
+
+// Le chat, loin des mots de départ : chat → souris → fromage, et le lait
+// qui fait taire la souris
+fn cat() -> Brain {
+    let mut brain = Brain::with_seed(24);
+    connect_with(
+        &mut brain,
+        "chat",
+        "souris",
+        Neurotransmitter::Glutamate,
+        2.9,
+    );
+    connect_with(
+        &mut brain,
+        "souris",
+        "chat",
+        Neurotransmitter::Glutamate,
+        0.2,
+    );
+    connect_with(
+        &mut brain,
+        "souris",
+        "fromage",
+        Neurotransmitter::Glutamate,
+        2.8,
+    );
+    connect_with(&mut brain, "lait", "souris", Neurotransmitter::Gaba, 1.5);
+    brain
+}
+
+fn count(text: &str, pattern: &str) -> usize {
+    text.matches(pattern).count()
+}
+
+#[test]
+fn whole_brain_in_every_format() {
+    let brain = cat();
+    let (nodes, edges) = (brain.vocab.len(), brain.synapses.len());
+    let filter = GraphFilter::default();
+
+    let dot = export_graph(&brain, &filter, GraphFormat::Dot);
+    assert!(dot.starts_with("digraph brain {"));
+    assert_eq!(count(&dot, "[label="), nodes);
+    assert_eq!(count(&dot, " -> "), edges);
+
+    let graphml = export_graph(&brain, &filter, GraphFormat::GraphMl);
+    assert_eq!(count(&graphml, "<node "), nodes);
+    assert_eq!(count(&graphml, "<edge "), edges);
+    assert!(graphml.contains(r#"edgedefault="directed""#));
+
+    let gexf = export_graph(&brain, &filter, GraphFormat::Gexf);
+    assert_eq!(count(&gexf, "<node "), nodes);
+    assert_eq!(count(&gexf, "<edge "), edges);
+    assert!(gexf.contains(r#"label="fromage""#));
+}
+
+#[test]
+fn attributes_describe_neurons_and_synapses() {
+    let brain = cat();
+    let filter = GraphFilter::default().with_ego("lait", 1);
+    let dot = export_graph(&brain, &filter, GraphFormat::Dot);
+    assert!(dot.contains("threshold="), "{}", dot);
+    assert!(dot.contains("fired_count=0"), "{}", dot);
+    assert!(dot.contains("composition=\"\""), "{}", dot);
+    assert!(
+        dot.contains(
+            r#"strength=1.5, neurotransmitter="GABA", direction=unidirectional, color=red"#
+        ),
+        "{}",
+        dot
+    );
+
+    let filter = GraphFilter::default().with_ego("chat", 1);
+    let graphml = export_graph(&brain, &filter, GraphFormat::GraphMl);
+    assert!(
+        graphml.contains(r#"<data key="strength">2.9</data>"#),
+        "{}",
+        graphml
+    );
+    assert!(graphml.contains(r#"<data key="direction">bidirectional</data>"#));
+}
+
+#[test]
+fn top_k_keeps_the_strongest_synapses() {
+    let brain = cat();
+    let filter = GraphFilter::default().with_top_k(2);
+    let dot = export_graph(&brain, &filter, GraphFormat::Dot);
+    assert_eq!(count(&dot, " -> "), 2);
+    assert_eq!(count(&dot, "[label="), 3);
+    for word in ["chat", "souris", "fromage"] {
+        assert!(dot.contains(&format!("label=\"{}\"", word)), "{}", dot);
+    }
+}
+
+#[test]
+fn ego_network_grows_with_the_radius() {
+    let brain = cat();
+    let words = |radius: usize| {
+        let filter = GraphFilter::default().with_ego("chat", radius);
+        count(&export_graph(&brain, &filter, GraphFormat::Gexf), "<node ")
+    };
+    assert_eq!(words(0), 1);
+    assert_eq!(words(1), 2); // chat, souris
+    assert_eq!(words(2), 4); // + fromage, lait
+
+    let unknown = GraphFilter::default().with_ego("chien", 3);
+    let dot = export_graph(&brain, &unknown, GraphFormat::Dot);
+    assert_eq!(dot, "digraph brain {\n}\n");
+}
+
+#[test]
+fn labels_are_escaped() {
+    let mut brain = Brain::with_seed(1);
+    connect_with(
+        &mut brain,
+        "l'eau",
+        "<\"&>",
+        Neurotransmitter::Glutamate,
+        1.0,
+    );
+    let filter = GraphFilter::default().with_ego("l'eau", 1);
+
+    let gexf = export_graph(&brain, &filter, GraphFormat::Gexf);
+    assert!(gexf.contains("label=\"l&apos;eau\""), "{}", gexf);
+    assert!(gexf.contains("label=\"&lt;&quot;&amp;&gt;\""), "{}", gexf);
+    let dot = export_graph(&brain, &filter, GraphFormat::Dot);
+    assert!(dot.contains(r#"label="<\"&>""#), "{}", dot);
+}
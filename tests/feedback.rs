@@ -0,0 +1,67 @@
+mod common;
+
+use ai_baby::brain::feedback_score;
+use common::{replied, strength};
+
+#[test]
+fn reactions_are_scored() {
+    let cases: &[(&str, f32)] = &[
+        ("/good", 1.0),
+        ("/bad", -1.0),
+        ("non /good", 1.0),
+        ("bien !", 0.5),
+        ("bravo, super", 1.0),
+        ("non, c'est nul", -1.0),
+        ("pas bien", -0.5),
+        ("pas mal", 0.5),
+        ("👍", 0.5),
+        ("bien 👎", 0.0),
+        ("😡😡😡", -1.0),
+        ("le chat dort", 0.0),
+    ];
+    for &(input, expected) in cases {
+        assert_eq!(feedback_score(input), expected, "{:?}", input);
+    }
+}
+
+#[test]
+fn praise_strengthens_exactly_the_synapses_behind_the_reply() {
+    let mut praised = replied();
+    let mut ignored = replied();
+    let chien_before = strength(&praised, "chien", "miaou");
+    praised.apply_feedback("/good", &["miaou".to_string()]);
+    ignored.apply_feedback("hmm", &["miaou".to_string()]);
+
+    assert!(strength(&praised, "chat", "miaou") > strength(&ignored, "chat", "miaou") + 0.1);
+    assert_eq!(strength(&praised, "chien", "miaou"), chien_before);
+}
+
+#[test]
+fn scolding_weakens_the_synapses_behind_the_reply() {
+    let mut scolded = replied();
+    let before = strength(&scolded, "chat", "miaou");
+    scolded.apply_feedback("/bad", &["miaou".to_string()]);
+    assert!(strength(&scolded, "chat", "miaou") < before - 0.1);
+}
+
+#[test]
+fn stress_amplifies_scolding() {
+    let mut calm = replied();
+    let mut stressed = replied();
+    stressed.modulator.stress = 0.9;
+    let before = strength(&calm, "chat", "miaou");
+    calm.apply_feedback("/bad", &["miaou".to_string()]);
+    stressed.apply_feedback("/bad", &["miaou".to_string()]);
+    assert!(
+        before - strength(&stressed, "chat", "miaou") > before - strength(&calm, "chat", "miaou")
+    );
+}
+
+#[test]
+fn feedback_judges_the_previous_reply_and_commands_are_not_learned() {
+    let mut brain = replied();
+    let before = strength(&brain, "chat", "miaou");
+    brain.interact("/good");
+    assert!(strength(&brain, "chat", "miaou") > before);
+    assert!(brain.vocab.id("good").is_none());
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use ai_baby::brain::{Brain, NeuronId, SynapseKey};
+use common::{connect, seed, sentence};
+
+fn id(brain: &Brain, w: &str) -> NeuronId {
+    brain.vocab.id(w).unwrap()
+}
+
+#[test]
+fn replies_follow_the_order_in_which_words_light_up() {
+    let mut brain = sentence();
+    assert_eq!(brain.generate_reply(&seed("maman")), "donne le biberon");
+
+    let used = brain.last_reply_synapses.clone();
+    let expected: Vec<SynapseKey> = [("maman", "donne"), ("donne", "le"), ("le", "biberon")]
+        .iter()
+        .map(|&(pre, post)| SynapseKey::new(id(&brain, pre), id(&brain, post)))
+        .collect();
+    assert_eq!(used, expected);
+}
+
+#[test]
+fn replies_respect_the_maximum_length() {
+    let mut brain = sentence();
+    brain.generation.max_len = 2;
+    assert_eq!(brain.generate_reply(&seed("maman")), "donne le");
+}
+
+#[test]
+fn unknown_or_isolated_seeds_give_an_empty_reply() {
+    let mut brain = sentence();
+    assert_eq!(brain.generate_reply(&seed("biberon")), "");
+    assert_eq!(brain.generate_reply(&seed("zèbre")), "");
+}
+
+// Nombre de fois où le mot le plus fort sort en premier
+fn favourite_first(noradrenaline: f32, dopamine: f32) -> usize {
+    let mut brain = Brain::with_seed(18);
+    connect(&mut brain, "chat", "miaou", 1.0);
+    connect(&mut brain, "chat", "croquettes", 0.8);
+    brain.modulator.noradrenaline = noradrenaline;
+    brain.modulator.dopamine = dopamine;
+    (0..200)
+        .filter(|_| brain.generate_reply(&seed("chat")).starts_with("miaou"))
+        .count()
+}
+
+#[test]
+fn noradrenaline_focuses_and_dopamine_explores() {
+    let focused = favourite_first(1.0, 0.0);
+    let exploring = favourite_first(0.0, 1.0);
+    assert!(focused > 190, "{}", focused);
+    assert!(exploring < 170, "{}", exploring);
+    assert!(exploring > 80, "{}", exploring);
+}
+
+#[test]
+fn sampling_is_reproducible_from_the_seed() {
+    let replies = |rng_seed: u64| {
+        let mut brain = Brain::with_seed(rng_seed);
+        connect(&mut brain, "chat", "miaou", 1.0);
+        connect(&mut brain, "chat", "croquettes", 0.9);
+        brain.modulator.dopamine = 1.0;
+        (0..20)
+            .map(|_| brain.generate_reply(&seed("chat")))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(replies(5), replies(5));
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use ai_baby::{Brain, SynapseKey};
+use common::{connect, word};
+
+const SIZE: usize = 30;
+const EPISODES: usize = 700;
+
+// Réseau tout-à-tout très excitateur, seuils bas : tout s'embrase
+fn runaway(homeostasis: bool) -> (Brain, Vec<String>) {
+    let mut brain = Brain::with_seed(1);
+    brain.homeostasis.enabled = homeostasis;
+
+    let words: Vec<String> = (0..SIZE).map(word).collect();
+    for pre in &words {
+        for post in &words {
+            if pre != post {
+                connect(&mut brain, pre, post, 2.0);
+            }
+        }
+    }
+    for n in brain.neurons.iter_mut() {
+        n.threshold = 0.3;
+        n.v = 1.0;
+    }
+    (brain, words)
+}
+
+// Taux de décharge moyen (spikes par neurone et par tick) des derniers
+// épisodes, quand quelques mots sont stimulés à chaque épisode
+fn late_rate(homeostasis: bool) -> (Brain, f32) {
+    let (mut brain, words) = runaway(homeostasis);
+    let (mut spikes, mut ticks) = (0, 0);
+    for episode in 0..EPISODES {
+        let history = brain.run_spiking(&words[..5], 30);
+        if episode >= EPISODES - 200 {
+            spikes += history.iter().map(|fired| fired.len()).sum::<usize>();
+            ticks += history.len();
+        }
+        brain.learn_from_spikes(&history);
+    }
+    (brain, spikes as f32 / (ticks * SIZE) as f32)
+}
+
+#[test]
+fn runaway_excitation_settles_near_the_target_rate() {
+    let (brain, rate) = late_rate(true);
+    let target = brain.homeostasis.target_rate;
+    assert!(
+        rate > target / 2.0 && rate < target * 2.0,
+        "taux {} pour une cible de {}",
+        rate,
+        target
+    );
+    for n in &brain.neurons {
+        assert!(n.threshold < brain.homeostasis.max_threshold);
+        assert!(n.gain > brain.homeostasis.min_gain);
+    }
+}
+
+#[test]
+fn without_homeostasis_runaway_excitation_saturates() {
+    let (brain, rate) = late_rate(false);
+    assert!(rate > brain.homeostasis.target_rate * 3.0, "taux {}", rate);
+}
+
+#[test]
+fn silent_neurons_become_more_excitable() {
+    let mut brain = Brain::with_seed(2);
+    let maman = brain.vocab.id("maman").unwrap();
+    let papa = brain.vocab.id("papa").unwrap();
+    let key = SynapseKey::new(maman, papa);
+    let (threshold, gain, strength) = (
+        brain.neurons[papa.index()].threshold,
+        brain.neurons[papa.index()].gain,
+        brain.synapses[&key].strength,
+    );
+
+    for _ in 0..50 {
+        brain.learn_from_spikes(&[Default::default()]);
+    }
+
+    let papa = &brain.neurons[papa.index()];
+    assert!(papa.threshold < threshold);
+    assert!(papa.gain > gain);
+    assert!(brain.synapses[&key].strength > strength);
+}
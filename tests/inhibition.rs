@@ -0,0 +1,98 @@
+mod common;
+
+use ai_baby::Neurotransmitter;
+use ai_baby::brain::{Brain, NeuronId, SynapseKey};
+use common::{connect, connect_with, neuron};
+use std::collections::BTreeSet;
+
+fn inhibitory(brain: &Brain, pre: NeuronId, post: NeuronId) -> Option<f32> {
+    brain
+        .synapses
+        .get(&SynapseKey::new(pre, post))
+        .filter(|syn| syn.is_inhibitory())
+        .map(|syn| syn.strength)
+}
+
+// "chat" appelle deux réponses concurrentes
+fn cat() -> (Brain, [NeuronId; 3]) {
+    let mut brain = Brain::with_seed(15);
+    let chat = neuron(&mut brain, "chat");
+    let miaou = neuron(&mut brain, "miaou");
+    let croquettes = neuron(&mut brain, "croquettes");
+    connect(&mut brain, "chat", "miaou", 1.0);
+    connect(&mut brain, "chat", "croquettes", 0.8);
+    (brain, [chat, miaou, croquettes])
+}
+
+#[test]
+fn competing_words_grow_mutual_inhibition() {
+    let (mut brain, [chat, miaou, croquettes]) = cat();
+    let history = vec![BTreeSet::from([chat]), BTreeSet::from([miaou, croquettes])];
+    brain.learn_from_spikes(&history);
+
+    assert!(inhibitory(&brain, miaou, croquettes).is_some());
+    assert!(inhibitory(&brain, croquettes, miaou).is_some());
+    // Les associations existantes restent excitatrices
+    assert!(inhibitory(&brain, chat, miaou).is_none());
+}
+
+#[test]
+fn stress_turns_co_activation_into_inhibition() {
+    let mut brain = Brain::with_seed(15);
+    let a = neuron(&mut brain, "bruit");
+    let b = neuron(&mut brain, "cri");
+    let history = vec![BTreeSet::from([a, b])];
+
+    brain.learn_from_spikes(&history);
+    assert!(inhibitory(&brain, a, b).is_none());
+
+    brain.modulator.stress = 0.8;
+    brain.learn_from_spikes(&history);
+    assert!(inhibitory(&brain, a, b).is_some());
+    assert!(inhibitory(&brain, b, a).is_some());
+}
+
+#[test]
+fn inhibitory_synapses_stay_inhibitory_and_bounded() {
+    let mut brain = Brain::with_seed(15);
+    let a = neuron(&mut brain, "non");
+    let b = neuron(&mut brain, "encore");
+    connect_with(&mut brain, "non", "encore", Neurotransmitter::Gaba, 1.0);
+    brain.modulator.stress = 0.9;
+    brain.inh_max = 1.2;
+
+    // `b` décharge bien au-dessus de sa cible : l'inhibition monte
+    for _ in 0..200 {
+        brain.modulator.stress = 0.9;
+        brain.learn_from_spikes(&[BTreeSet::from([a]), BTreeSet::from([b])]);
+    }
+    let strength = inhibitory(&brain, a, b).expect("synapse restée inhibitrice");
+    assert!(strength > 1.0 && strength <= brain.inh_max);
+    // STDP ne crée pas d'arête excitatrice en sens inverse
+    assert!(
+        brain
+            .synapses
+            .get(&SynapseKey::new(b, a))
+            .is_none_or(|syn| syn.is_inhibitory())
+    );
+}
+
+#[test]
+fn lateral_inhibition_makes_replies_selective() {
+    let (mut brain, _) = cat();
+    // Concentré : le mot le plus excité sort en premier
+    brain.modulator.noradrenaline = 1.0;
+    assert_eq!(
+        brain.generate_reply(&["chat".to_string()]),
+        "miaou croquettes"
+    );
+
+    connect_with(
+        &mut brain,
+        "miaou",
+        "croquettes",
+        Neurotransmitter::Gaba,
+        1.0,
+    );
+    assert_eq!(brain.generate_reply(&["chat".to_string()]), "miaou");
+}
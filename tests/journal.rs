@@ -0,0 +1,105 @@
+mod common;
+
+use common::temp_path;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+// Lance le REPL sur `brain` avec ces options et ces lignes tapées
+fn session(brain: &Path, args: &[&str], lines: &[&str]) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ai_baby"))
+        .arg("--brain")
+        .arg(brain)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    for line in lines {
+        writeln!(stdin, "{}", line).unwrap();
+    }
+    drop(stdin);
+    assert!(child.wait().unwrap().success());
+}
+
+fn run(args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_ai_baby"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    output
+}
+
+#[test]
+fn sessions_replay_from_the_seed_snapshot() {
+    let brain = temp_path("journal_brain.json");
+    let seed = temp_path("journal_brain.json.journal.seed");
+    let replayed = temp_path("journal_replayed.json");
+
+    // Cerveau neuf réglé, remis à zéro, rechargé avec d'autres réglages,
+    // et enfin repris tel quel
+    session(
+        &brain,
+        &["--seed", "7", "--tokenizer", "syllable"],
+        &["bonjour maman", "le chat dort", "bien !"],
+    );
+    assert!(seed.exists());
+    session(&brain, &[], &["/reset", "maman câlin"]);
+    session(
+        &brain,
+        &["--fold-accents", "--tokenizer", "ngram"],
+        &["bébé dort", "câlin bébé"],
+    );
+    session(&brain, &[], &["chat maman", "/good"]);
+
+    assert_replays(&brain, &replayed);
+
+    cleanup(&brain, &replayed);
+}
+
+#[test]
+fn a_session_ending_on_reset_still_replays() {
+    let brain = temp_path("journal_reset.json");
+    let replayed = temp_path("journal_reset_replayed.json");
+
+    session(&brain, &["--seed", "5"], &["bonjour maman", "le chat dort"]);
+    session(&brain, &[], &["/reset"]);
+    session(&brain, &[], &["le chat dort", "maman chat"]);
+
+    assert_replays(&brain, &replayed);
+    cleanup(&brain, &replayed);
+}
+
+#[test]
+fn a_backup_fallback_is_journaled() {
+    let brain = temp_path("journal_fallback.json");
+    let replayed = temp_path("journal_fallback_replayed.json");
+
+    // Le fichier principal est abîmé : la session suivante repart de `.1`,
+    // en retard d'un tour sur le journal
+    session(&brain, &["--seed", "9"], &["bonjour maman", "le chat dort"]);
+    fs::write(&brain, "{ pas un cerveau").unwrap();
+    session(&brain, &[], &["maman chat", "chat dort"]);
+
+    assert_replays(&brain, &replayed);
+    cleanup(&brain, &replayed);
+}
+
+// Le rejeu du journal redonne, octet pour octet, le cerveau sauvegardé
+fn assert_replays(brain: &Path, replayed: &Path) {
+    let journal = format!("{}.journal", brain.display());
+    let output = run(&["replay", &journal, "--out", replayed.to_str().unwrap()]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(", 0 divergences"), "{}", stdout);
+    assert_eq!(fs::read(replayed).unwrap(), fs::read(brain).unwrap());
+}
+
+fn cleanup(brain: &Path, replayed: &Path) {
+    fs::remove_file(replayed).unwrap();
+    for suffix in ["", ".journal", ".journal.seed", ".1", ".2", ".3"] {
+        let _ = fs::remove_file(format!("{}{}", brain.display(), suffix));
+    }
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use ai_baby::Brain;
+use ai_baby::logging::{self, LogFilter, Logger};
+use common::temp_path;
+use log::LevelFilter;
+use std::collections::BTreeSet;
+
+#[test]
+fn filters_parse_like_rust_log() {
+    let filter: LogFilter = "info,brain=debug,brain.spiking=trace,persist=off"
+        .parse()
+        .unwrap();
+    let cases = [
+        (logging::SPIKING, LevelFilter::Trace),
+        (logging::LEARNING, LevelFilter::Debug),
+        (logging::REPLY, LevelFilter::Debug),
+        (logging::MODULATOR, LevelFilter::Info),
+        (logging::PERSIST, LevelFilter::Off),
+        ("brainstorm", LevelFilter::Info),
+    ];
+    for (target, level) in cases {
+        assert_eq!(filter.level(target), level, "{}", target);
+    }
+    assert_eq!(filter.max_level(), LevelFilter::Trace);
+
+    let quiet = LogFilter::default();
+    assert_eq!(quiet.level(logging::SPIKING), LevelFilter::Warn);
+    assert_eq!("".parse::<LogFilter>().unwrap(), quiet);
+    assert!("brain=bavard".parse::<LogFilter>().is_err());
+}
+
+#[test]
+fn json_lines_file_records_targets_and_levels() {
+    let path = temp_path("log.jsonl");
+    let _ = std::fs::remove_file(&path);
+    let filter: LogFilter = "off,brain=debug".parse().unwrap();
+    Logger::new(filter).with_file(&path).unwrap().install();
+
+    let mut brain = Brain::with_seed(3);
+    brain.interact("bonjour maman");
+    log::logger().flush();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    let mut targets = BTreeSet::new();
+    for line in text.lines() {
+        let entry: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert!(entry["ts_ms"].is_u64());
+        assert!(entry["message"].is_string());
+        assert_ne!(entry["level"], "TRACE");
+        targets.insert(entry["target"].as_str().unwrap().to_string());
+    }
+    // Le modulateur est coupé par `off`, les cibles du cerveau passent
+    assert!(targets.contains(logging::SPIKING), "{:?}", targets);
+    assert!(targets.contains(logging::REPLY), "{:?}", targets);
+    assert!(!targets.contains(logging::MODULATOR), "{:?}", targets);
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use ai_baby::Brain;
+use ai_baby::brain::metrics::{HISTOGRAM_BINS, Phase};
+use ai_baby::brain::{MetricsFormat, MetricsRecorder};
+use ai_baby::persist::BrainSnapshot;
+use common::temp_path;
+
+const TRANSCRIPT: &[&str] = &["bonjour maman", "le chat dort", "maman chat", "bien !"];
+
+#[test]
+fn counters_follow_ticks_spikes_and_growth() {
+    let mut brain = Brain::with_seed(1);
+    let neurons = brain.neurons.len();
+    let synapses = brain.synapses.len();
+
+    let history = brain.run_spiking(&["chat".into(), "dort".into()], 3);
+    brain.learn_from_spikes(&history);
+
+    let counters = &brain.metrics.counters;
+    assert_eq!(counters.ticks, history.len() as u64);
+    assert_eq!(
+        counters.spikes,
+        history.iter().map(|fired| fired.len() as u64).sum::<u64>()
+    );
+    assert_eq!(counters.learning_steps, 1);
+    assert_eq!(
+        counters.neurons_created,
+        (brain.neurons.len() - neurons) as u64
+    );
+    assert_eq!(
+        counters.synapses_created,
+        (brain.synapses.len() - synapses) as u64
+    );
+    assert_eq!(counters.neurons_created, 2);
+}
+
+#[test]
+fn growth_outside_spiking_is_counted() {
+    let mut brain = Brain::with_seed(3);
+    let neurons = brain.neurons.len();
+    let synapses = brain.synapses.len();
+
+    // "bien" naît du feedback, avant tout spike
+    for line in TRANSCRIPT {
+        brain.interact(line);
+    }
+
+    let counters = &brain.metrics.counters;
+    assert_eq!(
+        counters.neurons_created,
+        (brain.neurons.len() - neurons) as u64
+    );
+    assert_eq!(
+        counters.synapses_created,
+        (brain.synapses.len() - synapses) as u64
+    );
+}
+
+#[test]
+fn samples_describe_the_network() {
+    let mut brain = Brain::with_seed(2);
+    brain.metrics.recorder = Some(MetricsRecorder::in_memory(8));
+    for line in TRANSCRIPT {
+        brain.interact(line);
+    }
+
+    let recorder = brain.metrics.recorder.as_ref().unwrap();
+    let samples: Vec<_> = recorder.recent().collect();
+    assert_eq!(samples.len(), 8);
+    let last = samples.last().unwrap();
+    assert_eq!(last.phase, Phase::Learning);
+    assert_eq!(last.tick, brain.metrics.counters.ticks);
+    assert_eq!(last.neurons, brain.neurons.len());
+    assert_eq!(
+        last.weights.iter().sum::<u32>() as usize,
+        brain.synapses.len()
+    );
+    assert!(last.v_max >= last.v_mean);
+    assert_eq!(last.dopamine, brain.modulator.dopamine);
+}
+
+#[test]
+fn recording_does_not_change_the_simulation() {
+    let raise = |record: bool| {
+        let mut brain = Brain::with_seed(5);
+        if record {
+            brain.metrics.recorder = Some(MetricsRecorder::in_memory(4));
+        }
+        for line in TRANSCRIPT {
+            brain.interact(line);
+        }
+        serde_json::to_string(&BrainSnapshot::from(&brain)).unwrap()
+    };
+    assert_eq!(raise(false), raise(true));
+}
+
+#[test]
+fn csv_and_json_lines_files() {
+    for (name, format) in [
+        ("metrics.csv", MetricsFormat::Csv),
+        ("metrics.jsonl", MetricsFormat::JsonLines),
+    ] {
+        let path = temp_path(name);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(MetricsFormat::from_path(&path), format);
+
+        let mut brain = Brain::with_seed(3);
+        brain.metrics.recorder = Some(MetricsRecorder::to_file(&path, format).unwrap());
+        brain.interact("bonjour maman");
+        let steps = brain.metrics.counters.ticks + brain.metrics.counters.learning_steps;
+        drop(brain);
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let lines: Vec<&str> = text.lines().collect();
+        match format {
+            MetricsFormat::Csv => {
+                assert_eq!(lines.len() as u64, steps + 1);
+                assert!(lines[0].starts_with("phase,turn,tick,fired"));
+                let columns = lines[0].split(',').count();
+                assert!(lines.iter().all(|l| l.split(',').count() == columns));
+                assert!(lines[0].ends_with(&format!("w{}", HISTOGRAM_BINS - 1)));
+            }
+            MetricsFormat::JsonLines => {
+                assert_eq!(lines.len() as u64, steps);
+                for line in lines {
+                    let sample: serde_json::Value = serde_json::from_str(line).unwrap();
+                    assert_eq!(sample["weights"].as_array().unwrap().len(), HISTOGRAM_BINS);
+                }
+            }
+        }
+    }
+}
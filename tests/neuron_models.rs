@@ -0,0 +1,93 @@
+use ai_baby::neuron_model::Lif;
+use ai_baby::persist::BrainSnapshot;
+use ai_baby::{Brain, Composition, Neuron, NeuronModel, NeuronModelKind};
+
+const MODELS: &[&str] = &["lif", "rs", "ib", "ch", "adex"];
+
+// Un neurone isolé sous entrée constante : ticks où il a spiké
+fn drive(model: NeuronModelKind, input: f32, ticks: usize) -> Vec<usize> {
+    let mut n = Neuron::new(1.0, Composition::default());
+    let mut spikes = vec![];
+    for t in 0..ticks {
+        if n.refractory > 0 {
+            n.refractory -= 1;
+            continue;
+        }
+        model.integrate(&mut n, input);
+        if model.fires(&n) {
+            spikes.push(t);
+            model.reset(&mut n);
+            n.refractory = model.refractory();
+        }
+        assert!(n.v.is_finite() && n.u.is_finite());
+    }
+    spikes
+}
+
+fn model(name: &str) -> NeuronModelKind {
+    name.parse().unwrap()
+}
+
+#[test]
+fn every_model_rests_without_input_and_fires_when_driven() {
+    for name in MODELS {
+        assert!(drive(model(name), 0.0, 200).is_empty(), "{}", name);
+        assert!(!drive(model(name), 2.0, 200).is_empty(), "{}", name);
+
+        let n = Neuron::new(1.0, Composition::default());
+        assert!(model(name).settled(&n), "{}", name);
+    }
+}
+
+#[test]
+fn regular_spiking_adapts() {
+    let spikes = drive(model("rs"), 2.0, 200);
+    let first = spikes[1] - spikes[0];
+    let last = spikes[spikes.len() - 1] - spikes[spikes.len() - 2];
+    assert!(last > first, "intervalles {:?}", spikes);
+}
+
+#[test]
+fn lif_adapts_under_constant_input() {
+    let spikes = drive(model("lif"), 0.3, 200);
+    let first = spikes[1] - spikes[0];
+    let last = spikes[spikes.len() - 1] - spikes[spikes.len() - 2];
+    assert!(last > first, "intervalles {:?}", spikes);
+}
+
+#[test]
+fn lif_without_adaptation_fires_regularly() {
+    let lif = NeuronModelKind::Lif(Lif {
+        adaptation: 0.0,
+        ..Lif::default()
+    });
+    let spikes = drive(lif, 0.3, 200);
+    let intervals: Vec<usize> = spikes.windows(2).map(|w| w[1] - w[0]).collect();
+    assert!(
+        intervals.windows(2).all(|w| w[0] == w[1]),
+        "intervalles {:?}",
+        intervals
+    );
+}
+
+#[test]
+fn chattering_fires_more_than_regular_spiking() {
+    let rs = drive(model("rs"), 1.0, 200).len();
+    let ch = drive(model("ch"), 1.0, 200).len();
+    assert!(ch > 2 * rs, "rs {} ch {}", rs, ch);
+}
+
+#[test]
+fn models_survive_a_snapshot() {
+    let mut brain = Brain::with_seed(3);
+    brain.neuron_model = model("adex");
+    brain.neurons[0].model = Some(model("ib"));
+    brain.interact("maman papa");
+
+    let json = serde_json::to_string(&BrainSnapshot::from(&brain)).unwrap();
+    let reloaded: Brain = serde_json::from_str::<BrainSnapshot>(&json).unwrap().into();
+
+    assert_eq!(reloaded.neuron_model, brain.neuron_model);
+    assert_eq!(reloaded.neurons[0].model, Some(model("ib")));
+    assert_eq!(reloaded.neurons[1].model, None);
+}
@@ -0,0 +1,96 @@
+use ai_baby::Brain;
+use ai_baby::normalize::{Normalization, UnicodeForm};
+use ai_baby::tokenize;
+
+// « é » composé (U+00E9) et décomposé (e + U+0301)
+const BEBE_NFC: &str = "b\u{e9}b\u{e9}";
+const BEBE_NFD: &str = "be\u{301}be\u{301}";
+
+fn normalized(normalization: &Normalization, text: &str) -> Vec<String> {
+    tokenize(&normalization.apply(text))
+}
+
+#[test]
+fn default_pipeline_cases() {
+    let normalization = Normalization::default();
+    let cases: &[(&str, &[&str])] = &[
+        (BEBE_NFD, &[BEBE_NFC]),
+        (BEBE_NFC, &[BEBE_NFC]),
+        ("l'amour", &["l'", "amour"]),
+        ("L’amour", &["l'", "amour"]),
+        ("d'accord", &["d'", "accord"]),
+        ("j'ai faim", &["j'", "ai", "faim"]),
+        ("qu'il dort", &["qu'", "il", "dort"]),
+        ("jusqu'à demain", &["jusqu'à", "demain"]),
+        ("aujourd'hui", &["aujourd'hui"]),
+        ("maman,l'amour", &["maman", "l'", "amour"]),
+        ("(l'amour)", &["l'", "amour"]),
+        ("papa\tl'amour", &["papa", "l'", "amour"]),
+        ("oui\nj'ai faim", &["oui", "j'", "ai", "faim"]),
+        ("bravo !qu'il dort", &["bravo", "qu'", "il", "dort"]),
+        ("bravo 👍", &["bravo", "emoji_pouce"]),
+        ("👍🏽", &["emoji_pouce"]),
+        ("❤️maman", &["emoji_coeur", "maman"]),
+        ("🦄", &["emoji_1f984"]),
+    ];
+    for &(text, expected) in cases {
+        assert_eq!(normalized(&normalization, text), expected, "{:?}", text);
+    }
+}
+
+#[test]
+fn composed_and_decomposed_forms_tokenize_alike() {
+    for form in [UnicodeForm::Nfc, UnicodeForm::Nfd] {
+        let normalization = Normalization {
+            form,
+            ..Normalization::default()
+        };
+        let tokens = normalized(&normalization, BEBE_NFC);
+        assert_eq!(tokens, normalized(&normalization, BEBE_NFD), "{:?}", form);
+        assert_eq!(tokens.len(), 1, "{:?}", form);
+    }
+}
+
+#[test]
+fn accent_folding_and_switches() {
+    let cases: &[(Normalization, &str, &[&str])] = &[
+        (
+            Normalization {
+                fold_accents: true,
+                ..Normalization::default()
+            },
+            "Bébé a goûté",
+            &["bebe", "a", "goute"],
+        ),
+        (
+            Normalization {
+                split_elision: false,
+                ..Normalization::default()
+            },
+            "l'amour",
+            &["l'amour"],
+        ),
+        (
+            Normalization {
+                emoji: false,
+                ..Normalization::default()
+            },
+            "bravo 👍",
+            &["bravo"],
+        ),
+    ];
+    for (normalization, text, expected) in cases {
+        assert_eq!(normalized(normalization, text), *expected, "{:?}", text);
+    }
+}
+
+#[test]
+fn elided_words_share_their_neuron() {
+    let mut brain = Brain::with_seed(7);
+    brain.interact("l'amour");
+    let neurons = brain.neurons.len();
+    brain.interact("amour");
+    assert_eq!(brain.neurons.len(), neurons);
+    assert!(brain.vocab.id("amour").is_some());
+    assert!(brain.vocab.id("l'amour").is_none());
+}
@@ -0,0 +1,53 @@
+// Sans la feature `parallel`, le mode Parallel retombe sur le tick dense et
+// ces tests sont triviaux : les lancer avec `--features parallel`.
+
+mod common;
+
+use ai_baby::{Brain, SimulationMode};
+use common::snapshot_json;
+
+const SIZE: usize = 800;
+const FAN_OUT: usize = 8;
+const TICKS: usize = 40;
+
+fn network(seed: u64, simulation: SimulationMode) -> Brain {
+    common::network(seed, simulation, SIZE, FAN_OUT)
+}
+
+#[test]
+fn parallel_tick_is_bit_identical_to_dense() {
+    for seed in 0..3 {
+        let mut dense = network(seed, SimulationMode::Dense);
+        let mut parallel = network(seed, SimulationMode::Parallel);
+
+        let dense_history = dense.run_spiking(&[], TICKS);
+        let parallel_history = parallel.run_spiking(&[], TICKS);
+        assert!(dense_history.iter().any(|fired| !fired.is_empty()));
+        assert_eq!(dense_history, parallel_history, "graine {}", seed);
+
+        for (id, w) in dense.vocab.iter() {
+            let (d, p) = (&dense.neurons[id.index()], &parallel.neurons[id.index()]);
+            assert_eq!(d.v.to_bits(), p.v.to_bits(), "{}", w);
+            assert_eq!(
+                dense.threshold(id).to_bits(),
+                parallel.threshold(id).to_bits(),
+                "{}",
+                w
+            );
+        }
+    }
+}
+
+#[test]
+fn parallel_learning_is_bit_identical_to_dense() {
+    let mut dense = network(7, SimulationMode::Dense);
+    let mut parallel = network(7, SimulationMode::Parallel);
+
+    for brain in [&mut dense, &mut parallel] {
+        let history = brain.run_spiking(&[], TICKS);
+        brain.learn_from_spikes(&history);
+    }
+    // Le mode de simulation est le seul champ qui diffère
+    parallel.simulation = SimulationMode::Dense;
+    assert_eq!(snapshot_json(&dense), snapshot_json(&parallel));
+}
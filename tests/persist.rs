@@ -0,0 +1,194 @@
+mod common;
+
+use ai_baby::persist::{self, BrainSnapshot, Format, PersistError, SNAPSHOT_VERSION, binary};
+use ai_baby::{Brain, Composition, Modulator, Neuron, Neurotransmitter, Synapse};
+use common::{snapshot_json, temp_path};
+use serde_json::{Value, json};
+
+// Ancien document : `neurons` et `synapses` au format de sa version
+fn legacy(version: Option<u32>, neurons: Value, synapses: Value) -> Value {
+    let mut doc = json!({
+        "neurons": neurons,
+        "synapses": synapses,
+        "modulator": Modulator::new(),
+        "lr_exc": 0.05,
+        "lr_inh": 0.03,
+        "exc_max": 1.0,
+        "inh_max": 1.0,
+        "forget": 0.001,
+    });
+    if let Some(version) = version {
+        doc["version"] = json!(version);
+    }
+    doc
+}
+
+fn neuron(threshold: f32) -> Value {
+    json!(Neuron::new(threshold, Composition::default()))
+}
+
+fn synapse(strength: f32) -> Value {
+    json!(Synapse::new(Neurotransmitter::Glutamate, strength))
+}
+
+// Force de pre → post après chargement, si la synapse existe
+fn strength(brain: &Brain, pre: &str, post: &str) -> Option<f32> {
+    let key = ai_baby::SynapseKey::new(brain.vocab.id(pre)?, brain.vocab.id(post)?);
+    brain.synapses.get(&key).map(|s| s.strength)
+}
+
+fn load_doc(name: &str, doc: &Value) -> Result<Brain, PersistError> {
+    let path = temp_path(name);
+    std::fs::write(&path, serde_json::to_vec(doc).unwrap()).unwrap();
+    let brain = persist::load(&path);
+    let _ = std::fs::remove_file(&path);
+    brain
+}
+
+#[test]
+fn save_then_load_is_lossless() {
+    let mut brain = Brain::with_seed(3);
+    for line in ["bonjour maman", "le chat dort", "bien !"] {
+        brain.interact(line);
+    }
+
+    let path = temp_path("roundtrip.json");
+    persist::save(&brain, &path).unwrap();
+    let loaded = persist::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(snapshot_json(&loaded), snapshot_json(&brain));
+}
+
+#[test]
+fn v0_brain_is_migrated() {
+    let doc = legacy(
+        None,
+        json!({ "chat": neuron(0.5), "dort": neuron(0.7) }),
+        json!([[["chat", "dort"], synapse(0.4)]]),
+    );
+    let brain = load_doc("v0.json", &doc).unwrap();
+
+    // Ordre alphabétique des identifiants, arête devenue bidirectionnelle
+    assert_eq!(brain.vocab.id("chat").unwrap().index(), 0);
+    assert_eq!(brain.vocab.id("dort").unwrap().index(), 1);
+    assert_eq!(brain.neurons[1].threshold, 0.7);
+    assert_eq!(strength(&brain, "chat", "dort"), Some(0.4));
+    assert_eq!(strength(&brain, "dort", "chat"), Some(0.4));
+    assert_eq!(brain.synapses.len(), 2);
+}
+
+#[test]
+fn v1_symmetric_synapses_are_migrated() {
+    let doc = legacy(
+        Some(1),
+        json!({ "maman": neuron(0.5), "amour": neuron(0.6) }),
+        json!([{ "a": "amour", "b": "maman", "synapse": synapse(0.3) }]),
+    );
+    let brain = load_doc("v1.json", &doc).unwrap();
+
+    assert_eq!(brain.vocab.id("amour").unwrap().index(), 0);
+    assert_eq!(strength(&brain, "amour", "maman"), Some(0.3));
+    assert_eq!(strength(&brain, "maman", "amour"), Some(0.3));
+}
+
+#[test]
+fn v2_directed_synapses_are_migrated() {
+    let doc = legacy(
+        Some(2),
+        json!({ "dort": neuron(0.5), "chat": neuron(0.5) }),
+        json!([{ "pre": "chat", "post": "dort", "synapse": synapse(0.8) }]),
+    );
+    let brain = load_doc("v2.json", &doc).unwrap();
+
+    assert_eq!(brain.vocab.id("chat").unwrap().index(), 0);
+    assert_eq!(strength(&brain, "chat", "dort"), Some(0.8));
+    assert_eq!(strength(&brain, "dort", "chat"), None);
+
+    // Une fois migré, il se réécrit à la version courante
+    let snapshot = BrainSnapshot::from(&brain);
+    assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+}
+
+#[test]
+fn future_version_is_refused() {
+    let doc = legacy(Some(99), json!([]), json!([]));
+    match load_doc("v99.json", &doc) {
+        Err(PersistError::UnsupportedVersion(99)) => {}
+        other => panic!("attendu UnsupportedVersion(99), obtenu {:?}", other.err()),
+    }
+}
+
+#[test]
+fn missing_file_is_not_found() {
+    let err = persist::load(temp_path("absent.json")).err().unwrap();
+    assert!(err.is_not_found());
+
+    let err = load_doc("garbage.json", &json!("pas un cerveau"))
+        .err()
+        .unwrap();
+    assert!(!err.is_not_found());
+}
+
+#[test]
+fn binary_conversion_roundtrips_and_is_smaller() {
+    let mut brain = Brain::with_seed(4);
+    for line in ["bonjour maman", "le chat dort", "maman chat", "bien !"] {
+        brain.interact(line);
+    }
+    let json = temp_path("convert.json");
+    let bin = temp_path("convert.bin");
+    let back = temp_path("convert_back.json");
+    persist::save(&brain, &json).unwrap();
+
+    persist::convert(&json, &bin, Format::Binary).unwrap();
+    persist::convert(&bin, &back, Format::Json).unwrap();
+
+    let json_len = std::fs::metadata(&json).unwrap().len();
+    let bin_len = std::fs::metadata(&bin).unwrap().len();
+    let original = std::fs::read(&json).unwrap();
+    let converted = std::fs::read(&back).unwrap();
+    for path in [&json, &bin, &back] {
+        std::fs::remove_file(path).unwrap();
+    }
+
+    assert!(
+        bin_len < json_len,
+        "binaire {} ≥ JSON {}",
+        bin_len,
+        json_len
+    );
+    assert_eq!(converted, original);
+}
+
+#[test]
+fn binary_without_magic_is_corrupt() {
+    let brain = Brain::with_seed(5);
+    let bytes = binary::encode(BrainSnapshot::from(&brain)).unwrap();
+
+    let err = binary::decode(&bytes[binary::MAGIC.len()..]).err().unwrap();
+    assert!(matches!(err, PersistError::Corrupt(_)), "{}", err);
+}
+
+#[test]
+fn binary_word_index_out_of_range_is_corrupt() {
+    let mut brain = Brain::with_seed(6);
+    brain.interact("le chat dort");
+    // Sans l'état du générateur (octets bruts), le document passe en JSON
+    let mut snapshot = BrainSnapshot::from(&brain);
+    snapshot.rng = None;
+    let bytes = binary::encode(snapshot).unwrap();
+
+    // On retire le dernier mot de la table : une référence pointe dans le vide
+    let mut doc: Value = rmp_serde::from_slice(&bytes[binary::MAGIC.len()..]).unwrap();
+    doc["words"].as_array_mut().unwrap().pop();
+    let mut bytes = binary::MAGIC.to_vec();
+    bytes.extend(rmp_serde::to_vec_named(&doc).unwrap());
+
+    let err = binary::decode(&bytes).err().unwrap();
+    assert!(
+        matches!(&err, PersistError::Corrupt(msg) if msg.contains("hors de la table")),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use ai_baby::Neurotransmitter;
+use ai_baby::brain::{Brain, SynapseKey, ensure_synapse};
+use common::{neuron, replied, strength};
+use std::collections::BTreeSet;
+
+fn dopaminergic(brain: &Brain, pre: &str, post: &str) -> bool {
+    let key = SynapseKey::new(brain.vocab.id(pre).unwrap(), brain.vocab.id(post).unwrap());
+    brain
+        .synapses
+        .get(&key)
+        .is_some_and(|syn| syn.is_dopaminergic())
+}
+
+// Gain apporté par "bien" plutôt que "ok" après `delay` tours neutres
+fn approval_gain(delay: usize) -> f32 {
+    let mut approved = replied();
+    let mut neutral = replied();
+    for _ in 0..delay {
+        approved.interact("hmm");
+        neutral.interact("hmm");
+    }
+    approved.interact("bien !");
+    neutral.interact("ok");
+    strength(&approved, "chat", "miaou") - strength(&neutral, "chat", "miaou")
+}
+
+#[test]
+fn approval_after_a_reply_strengthens_the_synapses_that_produced_it() {
+    assert!(approval_gain(0) > 0.1);
+}
+
+#[test]
+fn eligibility_fades_so_late_approval_counts_less() {
+    assert!(approval_gain(5) < approval_gain(0));
+}
+
+#[test]
+fn approval_words_grow_dopaminergic_synapses() {
+    let mut brain = replied();
+    brain.interact("bien !");
+    assert!(dopaminergic(&brain, "bien", "miaou"));
+    assert!(strength(&brain, "bien", "miaou") <= brain.reward.approval);
+}
+
+#[test]
+fn approval_rewards_the_words_it_is_given() {
+    let mut brain = replied();
+    brain.apply_feedback("bien", &["chien".to_string()]);
+    assert!(dopaminergic(&brain, "bien", "chien"));
+    assert!(!dopaminergic(&brain, "bien", "miaou"));
+}
+
+#[test]
+fn dopaminergic_neurons_reward_their_targets_when_they_fire() {
+    let mut brain = Brain::with_seed(16);
+    let [chat, miaou, bien] = ["chat", "miaou", "bien"].map(|w| neuron(&mut brain, w));
+    let syn = ensure_synapse(
+        &mut brain.synapses,
+        chat,
+        miaou,
+        Neurotransmitter::Glutamate,
+    );
+    syn.strength = 1.0;
+    syn.eligibility = 1.0;
+    ensure_synapse(&mut brain.synapses, bien, miaou, Neurotransmitter::Dopamine).strength = 0.3;
+
+    let mut silent = Brain::with_seed(16);
+    silent.vocab = brain.vocab.clone();
+    silent.neurons = brain.neurons.clone();
+    silent.synapses = brain.synapses.clone();
+
+    brain.learn_from_spikes(&[BTreeSet::from([bien])]);
+    silent.learn_from_spikes(&[BTreeSet::new()]);
+    assert!(strength(&brain, "chat", "miaou") > strength(&silent, "chat", "miaou") + 0.1);
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use ai_baby::Brain;
+use common::strength;
+
+#[test]
+fn heard_words_spike_in_the_order_of_the_sentence() {
+    let mut brain = Brain::with_seed(1);
+    let interaction = brain.experience("maman amour");
+
+    assert_eq!(interaction.spikes[0], ["maman"]);
+    assert_eq!(interaction.spikes[1], ["amour"]);
+}
+
+#[test]
+fn word_order_strengthens_the_forward_synapse() {
+    let mut brain = Brain::with_seed(1);
+    // Câblage de naissance symétrique : les deux sens partent à égalité
+    assert_eq!(
+        strength(&brain, "maman", "amour"),
+        strength(&brain, "amour", "maman")
+    );
+
+    for _ in 0..3 {
+        brain.interact("maman amour");
+    }
+
+    let forward = strength(&brain, "maman", "amour");
+    let backward = strength(&brain, "amour", "maman");
+    assert!(forward > backward, "{} ≤ {}", forward, backward);
+}
@@ -0,0 +1,154 @@
+use ai_baby::brain::{SynapseStore, ensure_neuron, ensure_synapse};
+use ai_baby::persist::BrainSnapshot;
+use ai_baby::{Brain, Composition, NeuronId, Neurotransmitter, Synapse, SynapseKey};
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+
+const WORDS: &[&str] = &[
+    "maman", "papa", "bébé", "amour", "lait", "dodo", "chat", "zèbre",
+];
+
+fn word() -> impl Strategy<Value = String> {
+    prop::sample::select(WORDS).prop_map(String::from)
+}
+
+fn history() -> impl Strategy<Value = Vec<BTreeSet<String>>> {
+    prop::collection::vec(prop::collection::btree_set(word(), 0..6), 0..8)
+}
+
+// Les mots deviennent des neurones du cerveau, dans l'ordre d'apparition
+fn intern(brain: &mut Brain, history: &[BTreeSet<String>]) -> Vec<BTreeSet<NeuronId>> {
+    history
+        .iter()
+        .map(|fired| {
+            fired
+                .iter()
+                .map(|w| {
+                    ensure_neuron(
+                        &mut brain.vocab,
+                        &mut brain.neurons,
+                        w,
+                        Composition::default(),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Une modification du magasin de synapses
+#[derive(Clone, Debug)]
+enum Edit {
+    Insert(u32, u32),
+    Ensure(u32, u32),
+    Remove(u32, u32),
+    Clear,
+}
+
+fn edits() -> impl Strategy<Value = Vec<Edit>> {
+    let id = 0..8u32;
+    let edit = prop_oneof![
+        4 => (id.clone(), id.clone()).prop_map(|(pre, post)| Edit::Insert(pre, post)),
+        4 => (id.clone(), id.clone()).prop_map(|(pre, post)| Edit::Ensure(pre, post)),
+        4 => (id.clone(), id).prop_map(|(pre, post)| Edit::Remove(pre, post)),
+        1 => Just(Edit::Clear),
+    ];
+    prop::collection::vec(edit, 0..40)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn learning_keeps_strengths_bounded(seed in any::<u64>(), history in history()) {
+        let mut brain = Brain::with_seed(seed);
+        let history = intern(&mut brain, &history);
+        brain.learn_from_spikes(&history);
+
+        let max = brain.exc_max.max(brain.inh_max);
+        for syn in brain.synapses.values() {
+            prop_assert!(syn.strength.is_finite());
+            prop_assert!((0.0..=max).contains(&syn.strength));
+        }
+    }
+
+    #[test]
+    fn causal_pairs_get_a_pre_to_post_synapse(seed in any::<u64>(), history in history()) {
+        let mut brain = Brain::with_seed(seed);
+        let history = intern(&mut brain, &history);
+        brain.learn_from_spikes(&history);
+
+        let window = brain.stdp.window;
+        for (t, posts) in history.iter().enumerate() {
+            for pres in &history[t.saturating_sub(window)..t] {
+                for &pre in pres {
+                    for &post in posts.iter().filter(|&&post| post != pre) {
+                        prop_assert!(brain.synapses.contains_key(&SynapseKey::new(pre, post)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ensure_synapse_is_found_by_its_key_only(pre in word(), post in word()) {
+        prop_assume!(pre != post);
+        let mut brain = Brain::with_seed(0);
+        brain.synapses.clear();
+
+        let pre = brain.vocab.intern(&pre);
+        let post = brain.vocab.intern(&post);
+
+        ensure_synapse(&mut brain.synapses, pre, post, Neurotransmitter::Glutamate).strength = 1.25;
+
+        prop_assert_eq!(brain.synapses[&SynapseKey::new(pre, post)].strength, 1.25);
+        prop_assert!(!brain.synapses.contains_key(&SynapseKey::new(post, pre)));
+    }
+
+    #[test]
+    fn snapshots_preserve_every_key(seed in any::<u64>(), history in history()) {
+        let mut brain = Brain::with_seed(seed);
+        let history = intern(&mut brain, &history);
+        brain.learn_from_spikes(&history);
+
+        let json = serde_json::to_string(&BrainSnapshot::from(&brain)).unwrap();
+        let reloaded: Brain = serde_json::from_str::<BrainSnapshot>(&json).unwrap().into();
+
+        let before: Vec<&SynapseKey> = brain.synapses.keys().collect();
+        let after: Vec<&SynapseKey> = reloaded.synapses.keys().collect();
+        prop_assert_eq!(before, after);
+    }
+
+    #[test]
+    fn the_incoming_index_matches_the_map(edits in edits()) {
+        let mut store = SynapseStore::new();
+        for edit in edits {
+            match edit {
+                Edit::Insert(pre, post) => {
+                    let key = SynapseKey::new(NeuronId(pre), NeuronId(post));
+                    store.insert(key, Synapse::new(Neurotransmitter::Glutamate, 1.0));
+                }
+                Edit::Ensure(pre, post) => {
+                    ensure_synapse(&mut store, NeuronId(pre), NeuronId(post), Neurotransmitter::Gaba);
+                }
+                Edit::Remove(pre, post) => {
+                    store.remove(&SynapseKey::new(NeuronId(pre), NeuronId(post)));
+                }
+                Edit::Clear => store.clear(),
+            }
+
+            // Même voisinage que par un parcours complet de la map
+            for id in (0..8).map(NeuronId) {
+                let predecessors: BTreeSet<NeuronId> = store.predecessors(id).collect();
+                let scanned: BTreeSet<NeuronId> =
+                    store.keys().filter(|key| key.post == id).map(|key| key.pre).collect();
+                prop_assert_eq!(predecessors, scanned);
+
+                let successors: BTreeSet<NeuronId> = store.successors(id).collect();
+                let scanned: BTreeSet<NeuronId> =
+                    store.keys().filter(|key| key.pre == id).map(|key| key.post).collect();
+                prop_assert_eq!(successors, scanned);
+            }
+        }
+    }
+}
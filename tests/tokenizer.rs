@@ -0,0 +1,89 @@
+use ai_baby::brain::{Brain, ensure_neuron, ensure_synapse};
+use ai_baby::tokenizer::{NgramTokenizer, SyllableTokenizer, WordTokenizer, syllabify};
+use ai_baby::{Composition, Neurotransmitter, Tokenizer, TokenizerKind};
+
+#[test]
+fn french_words_split_into_syllables() {
+    let cases: &[(&str, &[&str])] = &[
+        ("maman", &["ma", "man"]),
+        ("papa", &["pa", "pa"]),
+        ("bébé", &["bé", "bé"]),
+        ("biberon", &["bi", "be", "ron"]),
+        ("donne", &["don", "ne"]),
+        ("table", &["ta", "ble"]),
+        ("rocher", &["ro", "cher"]),
+        ("montagne", &["mon", "ta", "gne"]),
+        ("oiseau", &["oi", "seau"]),
+        ("quatre", &["qua", "tre"]),
+        ("parti", &["par", "ti"]),
+        ("lait", &["lait"]),
+        ("mamaaan", &["ma", "man"]),
+    ];
+    for &(word, expected) in cases {
+        assert_eq!(syllabify(word), expected, "{}", word);
+    }
+}
+
+#[test]
+fn misspellings_share_units_with_the_known_word() {
+    let syllables = SyllableTokenizer;
+    assert_eq!(syllables.tokenize("Mamaaan !"), syllables.tokenize("maman"));
+    assert_eq!(syllables.tokenize("j'ai faim"), ["j", "ai", "faim"]);
+
+    let trigrams = NgramTokenizer::default();
+    let known = trigrams.tokenize("maman");
+    let typo = trigrams.tokenize("mamna");
+    assert_eq!(known, ["_ma", "mam", "ama", "man", "an_"]);
+    assert!(typo.iter().filter(|g| known.contains(g)).count() >= 2);
+}
+
+#[test]
+fn units_glue_back_into_text() {
+    assert_eq!(WordTokenizer.detokenize(&["le", "chat"]), "le chat");
+    assert_eq!(SyllableTokenizer.detokenize(&["ma", "pa"]), "mapa");
+
+    let trigrams = NgramTokenizer::default();
+    let grams = trigrams.tokenize("le chat");
+    let grams: Vec<&str> = grams.iter().map(String::as_str).collect();
+    assert_eq!(trigrams.detokenize(&grams), "le chat");
+}
+
+#[test]
+fn tokenizers_are_chosen_by_name() {
+    assert_eq!("word".parse(), Ok(TokenizerKind::Word));
+    assert_eq!("syllable".parse(), Ok(TokenizerKind::Syllable));
+    assert_eq!(
+        "ngram4".parse(),
+        Ok(TokenizerKind::Ngram(NgramTokenizer { n: 4 }))
+    );
+    assert!("ngram0".parse::<TokenizerKind>().is_err());
+    assert!("lettres".parse::<TokenizerKind>().is_err());
+
+    let json = serde_json::to_string(&TokenizerKind::Ngram(NgramTokenizer { n: 2 })).unwrap();
+    assert_eq!(
+        serde_json::from_str::<TokenizerKind>(&json).unwrap(),
+        TokenizerKind::Ngram(NgramTokenizer { n: 2 })
+    );
+}
+
+#[test]
+fn a_syllable_brain_invents_words_from_known_syllables() {
+    let mut brain = Brain::with_seed(20);
+    brain.tokenizer = TokenizerKind::Syllable;
+    brain.development.enabled = false;
+    brain.modulator.noradrenaline = 1.0;
+    // « pa » → « pi » appris de « papi », « pi » → « lou » de « pilou-pilou »
+    let [pa, pi, lou] = ["pa", "pi", "lou"].map(|w| {
+        ensure_neuron(
+            &mut brain.vocab,
+            &mut brain.neurons,
+            w,
+            Composition::default(),
+        )
+    });
+    ensure_synapse(&mut brain.synapses, pa, pi, Neurotransmitter::Glutamate).strength = 1.0;
+    ensure_synapse(&mut brain.synapses, pi, lou, Neurotransmitter::Glutamate).strength = 1.0;
+
+    assert_eq!(brain.interact("pa"), "pilou");
+    assert_eq!(brain.last_reply_words, ["pi", "lou"]);
+}